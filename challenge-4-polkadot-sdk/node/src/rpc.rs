@@ -22,8 +22,12 @@
 
 #![warn(missing_docs)]
 
+mod vesting;
+
 use jsonrpsee::RpcModule;
-use minimal_template_runtime::interface::{AccountId, Nonce, OpaqueBlock};
+use minimal_template_runtime::interface::{
+	AccountId, AssetId, Balance, BlockNumber, Nonce, OpaqueBlock,
+};
 use polkadot_sdk::{
 	sc_transaction_pool_api::TransactionPool,
 	sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata},
@@ -54,13 +58,22 @@ where
 		+ 'static,
 	C::Api: sp_block_builder::BlockBuilder<OpaqueBlock>,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<OpaqueBlock, AccountId, Nonce>,
+	C::Api: pallet_vesting::runtime_api::VestingApi<
+		OpaqueBlock,
+		AccountId,
+		BlockNumber,
+		Balance,
+		AssetId,
+	>,
 	P: TransactionPool + 'static,
 {
 	use polkadot_sdk::substrate_frame_rpc_system::{System, SystemApiServer};
+	use vesting::{Vesting, VestingApiServer};
 	let mut module = RpcModule::new(());
 	let FullDeps { client, pool } = deps;
 
 	module.merge(System::new(client.clone(), pool.clone()).into_rpc())?;
+	module.merge(Vesting::new(client.clone()).into_rpc())?;
 
 	Ok(module)
 }
@@ -0,0 +1,187 @@
+//! RPC methods for querying the vesting state of accounts through
+//! [`pallet_vesting::runtime_api::VestingApi`].
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorObject, ErrorObjectOwned},
+};
+use pallet_vesting::{runtime_api::VestingApi as VestingRuntimeApi, VestingSchedule};
+use polkadot_sdk::{
+	sp_api::ProvideRuntimeApi,
+	sp_blockchain::HeaderBackend,
+	sp_runtime::traits::Block as BlockT,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{marker::PhantomData, sync::Arc};
+
+/// Error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+/// Vesting RPC methods.
+#[rpc(server)]
+pub trait VestingApi<BlockHash, AccountId, BlockNumber, Balance, AssetId> {
+	/// The vesting schedules of `who`.
+	#[method(name = "vesting_schedules")]
+	fn schedules(
+		&self,
+		who: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<VestingSchedule<AccountId, BlockNumber, Balance>>>;
+
+	/// The amount that has vested for `who` across all schedules.
+	#[method(name = "vesting_vestedBalance")]
+	fn vested_balance(&self, who: AccountId, at: Option<BlockHash>) -> RpcResult<Balance>;
+
+	/// The amount that is still locked for `who` across all schedules.
+	#[method(name = "vesting_lockedBalance")]
+	fn locked_balance(&self, who: AccountId, at: Option<BlockHash>) -> RpcResult<Balance>;
+
+	/// The amount a `claim` by `who` would unlock right now.
+	#[method(name = "vesting_claimableBalance")]
+	fn claimable_balance(&self, who: AccountId, at: Option<BlockHash>) -> RpcResult<Balance>;
+
+	/// The next block at which more of `who`'s vesting unlocks.
+	#[method(name = "vesting_nextUnlock")]
+	fn next_unlock(&self, who: AccountId, at: Option<BlockHash>) -> RpcResult<Option<BlockNumber>>;
+
+	/// The coming unlocks of `who` as `(block, unlocked, cumulative)`, at most `max_entries`.
+	#[method(name = "vesting_unlockCalendar")]
+	fn unlock_calendar(
+		&self,
+		who: AccountId,
+		max_entries: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(BlockNumber, Balance, Balance)>>;
+
+	/// The vesting schedules of `who` in `asset`.
+	#[method(name = "vesting_assetSchedules")]
+	fn asset_schedules(
+		&self,
+		asset: AssetId,
+		who: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<VestingSchedule<AccountId, BlockNumber, Balance>>>;
+
+	/// The amount of `asset` that has vested for `who` across all schedules.
+	#[method(name = "vesting_assetVestedBalance")]
+	fn asset_vested_balance(
+		&self,
+		asset: AssetId,
+		who: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Balance>;
+
+	/// The amount of `asset` that is still locked for `who` across all schedules.
+	#[method(name = "vesting_assetLockedBalance")]
+	fn asset_locked_balance(
+		&self,
+		asset: AssetId,
+		who: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Balance>;
+}
+
+/// Implements [`VestingApiServer`] on top of the runtime API.
+pub struct Vesting<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Vesting<C, Block> {
+	/// Create a new instance of the vesting RPC.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, AccountId, BlockNumber, Balance, AssetId>
+	VestingApiServer<<Block as BlockT>::Hash, AccountId, BlockNumber, Balance, AssetId>
+	for Vesting<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: VestingRuntimeApi<Block, AccountId, BlockNumber, Balance, AssetId>,
+	AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	BlockNumber: Codec + Serialize + Send + Sync + 'static,
+	Balance: Codec + Serialize + Send + Sync + 'static,
+	AssetId: Codec + DeserializeOwned + Send + Sync + 'static,
+{
+	fn schedules(
+		&self,
+		who: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<VestingSchedule<AccountId, BlockNumber, Balance>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().vesting_schedules(at, who).map_err(runtime_error)
+	}
+
+	fn vested_balance(&self, who: AccountId, at: Option<Block::Hash>) -> RpcResult<Balance> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().vested_balance(at, who).map_err(runtime_error)
+	}
+
+	fn locked_balance(&self, who: AccountId, at: Option<Block::Hash>) -> RpcResult<Balance> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().locked_balance(at, who).map_err(runtime_error)
+	}
+
+	fn claimable_balance(&self, who: AccountId, at: Option<Block::Hash>) -> RpcResult<Balance> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().claimable_balance(at, who).map_err(runtime_error)
+	}
+
+	fn next_unlock(
+		&self,
+		who: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<BlockNumber>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().next_unlock(at, who).map_err(runtime_error)
+	}
+
+	fn unlock_calendar(
+		&self,
+		who: AccountId,
+		max_entries: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<(BlockNumber, Balance, Balance)>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().unlock_calendar(at, who, max_entries).map_err(runtime_error)
+	}
+
+	fn asset_schedules(
+		&self,
+		asset: AssetId,
+		who: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<VestingSchedule<AccountId, BlockNumber, Balance>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().asset_vesting_schedules(at, asset, who).map_err(runtime_error)
+	}
+
+	fn asset_vested_balance(
+		&self,
+		asset: AssetId,
+		who: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Balance> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().asset_vested_balance(at, asset, who).map_err(runtime_error)
+	}
+
+	fn asset_locked_balance(
+		&self,
+		asset: AssetId,
+		who: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Balance> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().asset_locked_balance(at, asset, who).map_err(runtime_error)
+	}
+}
+
+fn runtime_error(e: impl std::fmt::Debug) -> ErrorObjectOwned {
+	ErrorObject::owned(RUNTIME_ERROR, "Unable to query the vesting state.", Some(format!("{e:?}")))
+}
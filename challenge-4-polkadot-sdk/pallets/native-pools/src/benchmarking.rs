@@ -0,0 +1,126 @@
+//! Benchmarking setup for pallet-native-pools
+
+use super::*;
+use frame::benchmarking::prelude::*;
+use frame_system::RawOrigin;
+
+/// The amount every benchmark deposits
+fn deposit_amount<T: Config>() -> BalanceOf<T> {
+	T::Currency::minimum_balance().saturating_mul(1000u32.into())
+}
+
+/// Fund `who` with enough to deposit `deposit_amount` twice
+fn fund<T: Config>(who: &T::AccountId) {
+	T::Currency::set_balance(
+		who,
+		T::Currency::minimum_balance().saturating_add(deposit_amount::<T>().saturating_mul(2u32.into())),
+	);
+}
+
+/// Give `who` a deposit whose full amount is waiting in the reward pot as pending rewards
+fn deposit_with_rewards<T: Config>(who: &T::AccountId) -> Result<(), BenchmarkError> {
+	fund::<T>(who);
+	Pallet::<T>::deposit(RawOrigin::Signed(who.clone()).into(), deposit_amount::<T>())?;
+
+	T::Currency::set_balance(
+		&Pallet::<T>::account_id(),
+		T::Currency::minimum_balance().saturating_add(deposit_amount::<T>()),
+	);
+	AccRewardPerShare::<T>::put(Pallet::<T>::precision());
+	Ok(())
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn deposit_first() {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+
+		#[extrinsic_call]
+		deposit(RawOrigin::Signed(caller.clone()), deposit_amount::<T>());
+
+		assert_eq!(Deposits::<T>::get(&caller).map(|d| d.amount), Some(deposit_amount::<T>()));
+	}
+
+	#[benchmark]
+	fn deposit_top_up() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		deposit_with_rewards::<T>(&caller)?;
+
+		#[extrinsic_call]
+		deposit(RawOrigin::Signed(caller.clone()), deposit_amount::<T>());
+
+		assert_eq!(
+			Deposits::<T>::get(&caller).map(|d| d.amount),
+			Some(deposit_amount::<T>().saturating_mul(2u32.into()))
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn withdraw_partial() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		deposit_with_rewards::<T>(&caller)?;
+		let amount = deposit_amount::<T>() / 2u32.into();
+
+		#[extrinsic_call]
+		withdraw(RawOrigin::Signed(caller.clone()), Some(amount));
+
+		assert_eq!(
+			Deposits::<T>::get(&caller).map(|d| d.amount),
+			Some(deposit_amount::<T>().saturating_sub(amount))
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn withdraw_full() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		deposit_with_rewards::<T>(&caller)?;
+
+		#[extrinsic_call]
+		withdraw(RawOrigin::Signed(caller.clone()), None);
+
+		assert_eq!(Deposits::<T>::get(&caller), None);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn claim_rewards() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		deposit_with_rewards::<T>(&caller)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert_eq!(
+			Deposits::<T>::get(&caller).map(|d| d.reward_debt),
+			Some(deposit_amount::<T>())
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn deposit_rewards() -> Result<(), BenchmarkError> {
+		let depositor: T::AccountId = account("depositor", 0, 0);
+		fund::<T>(&depositor);
+		Pallet::<T>::deposit(RawOrigin::Signed(depositor).into(), deposit_amount::<T>())?;
+
+		let origin = T::RewardOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let who = frame_system::ensure_signed(origin.clone())
+			.map_err(|_| BenchmarkError::Weightless)?;
+		fund::<T>(&who);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, deposit_amount::<T>());
+
+		assert_eq!(TotalRewards::<T>::get(), deposit_amount::<T>());
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::ExtBuilder::build(), crate::mock::Runtime);
+}
@@ -3,27 +3,42 @@
 //! This pallet allows users to deposit native tokens into a pool and receive
 //! proportional daily rewards. Users can withdraw their deposits plus accumulated
 //! rewards at any time. Only authorized team members can deposit rewards.
+//!
+//! Deposits never leave the depositor's account, they are put on hold with
+//! [`HoldReason::PoolDeposit`]. Only the reward pot is kept in the pallet account.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame::prelude::*;
 use polkadot_sdk::polkadot_sdk_frame as frame;
 use polkadot_sdk::frame_support::{
-	traits::{Currency, ExistenceRequirement, Get},
+	traits::{
+		fungible::{Inspect, InspectHold, Mutate, MutateHold},
+		tokens::{Fortitude, Precision, Preservation},
+		Get,
+	},
 	PalletId,
 };
 use polkadot_sdk::sp_runtime::{
-	traits::{AccountIdConversion, Saturating, Zero}
+	traits::{AccountIdConversion, CheckedAdd, CheckedDiv, CheckedMul, Saturating, Zero},
+	SaturatedConversion,
 };
 
 // Re-export all pallet parts, this is needed to properly import the pallet into the runtime.
 pub use pallet::*;
 
 type BalanceOf<T> =
-	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
 
 type BlockNumberFor<T> = frame_system::pallet_prelude::BlockNumberFor<T>;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+mod mock;
+mod tests;
+pub mod weights;
+pub use weights::WeightInfo;
+
 /// Information about a user's deposit in the pool
 #[derive(Clone, Encode, Decode, MaxEncodedLen, TypeInfo, Debug, PartialEq)]
 pub struct DepositInfo<Balance, BlockNumber> {
@@ -41,21 +56,37 @@ pub mod pallet {
 
 	#[pallet::config]
 	pub trait Config: polkadot_sdk::frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as polkadot_sdk::frame_system::Config>::RuntimeEvent>;
 
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
 
-		type Currency: Currency<Self::AccountId>;
+		type Currency: Mutate<Self::AccountId>
+			+ MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
 
-		/// The pallet's account ID for holding pooled funds
+		/// The pallet's account ID for holding the reward pot
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
 
 		/// The origin that can deposit rewards (team members)
 		type RewardOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
+	/// A reason for the pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// Funds are deposited in the pool.
+		#[codec(index = 0)]
+		PoolDeposit,
+	}
+
 	/// Total amount deposited in the pool by all users
 	#[pallet::storage]
 	#[pallet::getter(fn total_deposited)]
@@ -82,11 +113,53 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Rewards that could not be paid on a top-up or withdrawal because the reward pot was short
+	#[pallet::storage]
+	#[pallet::getter(fn owed_rewards)]
+	pub type OwedRewards<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
 	/// Last block when rewards were updated
 	#[pallet::storage]
 	#[pallet::getter(fn last_reward_block)]
 	pub type LastRewardBlock<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
 
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Tokens were deposited into the pool
+		Deposited {
+			who: T::AccountId,
+			amount: BalanceOf<T>,
+			reward_paid: BalanceOf<T>,
+			reward_owed: BalanceOf<T>,
+			acc_reward_per_share: BalanceOf<T>,
+			total_deposited: BalanceOf<T>,
+		},
+		/// Tokens were withdrawn from the pool
+		Withdrawn {
+			who: T::AccountId,
+			amount: BalanceOf<T>,
+			reward_paid: BalanceOf<T>,
+			reward_owed: BalanceOf<T>,
+			acc_reward_per_share: BalanceOf<T>,
+			total_deposited: BalanceOf<T>,
+		},
+		/// Pending rewards were paid out without touching the deposit
+		RewardsClaimed {
+			who: T::AccountId,
+			reward_paid: BalanceOf<T>,
+			acc_reward_per_share: BalanceOf<T>,
+			total_deposited: BalanceOf<T>,
+		},
+		/// The team topped up the reward pot
+		RewardsDeposited {
+			who: T::AccountId,
+			amount: BalanceOf<T>,
+			acc_reward_per_share: BalanceOf<T>,
+			total_deposited: BalanceOf<T>,
+		},
+	}
 
 	#[pallet::error]
 	pub enum Error<T> {
@@ -100,149 +173,275 @@ pub mod pallet {
 		InsufficientPoolBalance,
 		/// Arithmetic overflow occurred
 		ArithmeticOverflow,
+		/// Nobody has a deposit in the pool to share the rewards
+		NoDeposits,
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Deposit native tokens into the pool
 		///
-		/// The dispatch origin for this call must be _Signed_.
+		/// The dispatch origin for this call must be _Signed_. A top-up pays out the rewards earned
+		/// so far, the part the reward pot cannot cover is kept in `OwedRewards` like on `withdraw`.
 		///
 		/// - `amount`: The amount of tokens to deposit
 		#[pallet::call_index(0)]
-		#[pallet::weight({10_000})]
+		#[pallet::weight(T::WeightInfo::deposit_first().max(T::WeightInfo::deposit_top_up()))]
 		pub fn deposit(
 			origin: OriginFor<T>,
 			amount: BalanceOf<T>,
 		) -> DispatchResult {
-			// TODO: Implement deposit functionality
-			// 1. Ensure origin is signed
-			// 2. Validate amount > 0 and user has sufficient balance
-			// 3. Update pool state
-			// 4. Transfer tokens from user to pool account
-			// 5. Update or create user's deposit info with proper reward_debt
-			// 6. Update total deposited amount
-			// 
-			// Hints:
-			// - Use Self::update_pool() before modifying state
-			// - Calculate reward_debt = amount × AccRewardPerShare / precision
-			// - Use Deposits::<T>::mutate() to handle existing vs new deposits
-			todo!()
+			let who = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			ensure!(
+				T::Currency::can_hold(&HoldReason::PoolDeposit.into(), &who, amount),
+				Error::<T>::InsufficientBalance
+			);
+
+			Self::update_pool()?;
+
+			// Pay out what the existing deposit earned so far, the reward debt is reset below.
+			let pending = match Deposits::<T>::get(&who) {
+				Some(_) => Self::calculate_pending_rewards(&who)?,
+				None => Zero::zero(),
+			};
+			let (reward_paid, reward_owed) = Self::pay_or_owe_reward(&who, pending)?;
+			T::Currency::hold(&HoldReason::PoolDeposit.into(), &who, amount)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			Deposits::<T>::try_mutate(&who, |maybe_deposit| -> DispatchResult {
+				let deposit = maybe_deposit.get_or_insert_with(|| DepositInfo {
+					amount: Zero::zero(),
+					deposit_block: now,
+					reward_debt: Zero::zero(),
+				});
+				deposit.amount =
+					deposit.amount.checked_add(&amount).ok_or(Error::<T>::ArithmeticOverflow)?;
+				deposit.reward_debt = Self::reward_debt(deposit.amount)?;
+				Ok(())
+			})?;
+			let total_deposited = TotalDeposited::<T>::try_mutate(|total| {
+				*total = total.checked_add(&amount).ok_or(Error::<T>::ArithmeticOverflow)?;
+				Ok::<_, DispatchError>(*total)
+			})?;
+
+			Self::deposit_event(Event::Deposited {
+				who,
+				amount,
+				reward_paid,
+				reward_owed,
+				acc_reward_per_share: AccRewardPerShare::<T>::get(),
+				total_deposited,
+			});
+			Ok(())
 		}
 
 		/// Withdraw tokens and rewards from the pool
 		///
-		/// The dispatch origin for this call must be _Signed_.
+		/// The dispatch origin for this call must be _Signed_. The deposit is always released, the
+		/// part of the rewards the reward pot cannot cover is kept in `OwedRewards` and paid by
+		/// `claim_rewards` later.
 		///
 		/// - `amount`: The amount of deposited tokens to withdraw (None for full withdrawal)
 		#[pallet::call_index(1)]
-		#[pallet::weight({10_000})]
+		#[pallet::weight(T::WeightInfo::withdraw_partial().max(T::WeightInfo::withdraw_full()))]
 		pub fn withdraw(
 			origin: OriginFor<T>,
 			amount: Option<BalanceOf<T>>,
 		) -> DispatchResult {
-			// TODO: Implement withdraw functionality
-			// 1. Ensure origin is signed and user has deposit
-			// 2. Update pool state
-			// 3. Calculate pending rewards
-			// 4. Determine withdrawal amount (use deposit amount if None)
-			// 5. Validate withdrawal amount and pool balance
-			// 6. Update user's deposit info (remove if full withdrawal)
-			// 7. Update total deposited
-			// 8. Transfer tokens + rewards back to user
-			//
-			// Hints:
-			// - total_withdrawal = withdraw_amount + pending_rewards
-			// - For partial withdrawal, recalculate reward_debt for remaining amount
-			// - Use Deposits::<T>::remove() for full withdrawal
-			todo!()
+			let who = ensure_signed(origin)?;
+			let mut deposit = Deposits::<T>::get(&who).ok_or(Error::<T>::NoDeposit)?;
+
+			Self::update_pool()?;
+			let pending = Self::calculate_pending_rewards(&who)?;
+
+			let withdraw_amount = amount.unwrap_or(deposit.amount);
+			ensure!(!withdraw_amount.is_zero(), Error::<T>::ZeroAmount);
+			ensure!(withdraw_amount <= deposit.amount, Error::<T>::InsufficientBalance);
+
+			deposit.amount = deposit.amount.saturating_sub(withdraw_amount);
+			if deposit.amount.is_zero() {
+				Deposits::<T>::remove(&who);
+			} else {
+				deposit.reward_debt = Self::reward_debt(deposit.amount)?;
+				Deposits::<T>::insert(&who, deposit);
+			}
+			let total_deposited = TotalDeposited::<T>::mutate(|total| {
+				*total = total.saturating_sub(withdraw_amount);
+				*total
+			});
+
+			T::Currency::release(
+				&HoldReason::PoolDeposit.into(),
+				&who,
+				withdraw_amount,
+				Precision::Exact,
+			)?;
+			let (reward_paid, reward_owed) = Self::pay_or_owe_reward(&who, pending)?;
+
+			Self::deposit_event(Event::Withdrawn {
+				who,
+				amount: withdraw_amount,
+				reward_paid,
+				reward_owed,
+				acc_reward_per_share: AccRewardPerShare::<T>::get(),
+				total_deposited,
+			});
+			Ok(())
 		}
 
 		/// Claim pending rewards without withdrawing deposit
 		///
-		/// The dispatch origin for this call must be _Signed_.
+		/// The dispatch origin for this call must be _Signed_. Rewards still owed from earlier
+		/// withdrawals are paid as well, also once the whole deposit has been withdrawn.
 		#[pallet::call_index(2)]
-		#[pallet::weight({10_000})]
+		#[pallet::weight(T::WeightInfo::claim_rewards())]
 		pub fn claim_rewards(origin: OriginFor<T>) -> DispatchResult {
-			// TODO: Implement claim_rewards functionality
-			// 1. Ensure origin is signed and user has deposit
-			// 2. Update pool state
-			// 3. Calculate pending rewards
-			// 4. Validate rewards > 0 and pool has sufficient balance
-			// 5. Update user's reward_debt to current level
-			// 6. Transfer rewards to user
-			//
-			// Hints:
-			// - Only transfer rewards, keep deposit amount unchanged
-			// - Update reward_debt = amount × AccRewardPerShare / precision
-			todo!()
+			let who = ensure_signed(origin)?;
+			let maybe_deposit = Deposits::<T>::get(&who);
+			let owed = OwedRewards::<T>::get(&who);
+			ensure!(maybe_deposit.is_some() || !owed.is_zero(), Error::<T>::NoDeposit);
+
+			Self::update_pool()?;
+			let pending = match maybe_deposit {
+				Some(mut deposit) => {
+					let pending = Self::calculate_pending_rewards(&who)?;
+					deposit.reward_debt = Self::reward_debt(deposit.amount)?;
+					Deposits::<T>::insert(&who, deposit);
+					pending
+				},
+				None => Zero::zero(),
+			};
+			let reward_paid = pending.checked_add(&owed).ok_or(Error::<T>::ArithmeticOverflow)?;
+			ensure!(!reward_paid.is_zero(), Error::<T>::ZeroAmount);
+
+			OwedRewards::<T>::remove(&who);
+			Self::pay_reward(&who, reward_paid)?;
+
+			Self::deposit_event(Event::RewardsClaimed {
+				who,
+				reward_paid,
+				acc_reward_per_share: AccRewardPerShare::<T>::get(),
+				total_deposited: TotalDeposited::<T>::get(),
+			});
+			Ok(())
 		}
 
 		/// Deposit rewards into the pool (team only)
 		///
-		/// The dispatch origin for this call must be from `RewardOrigin`.
+		/// The dispatch origin for this call must be from `RewardOrigin`. Fails with `NoDeposits`
+		/// while the pool is empty.
 		///
 		/// - `amount`: The amount of rewards to deposit
 		#[pallet::call_index(3)]
-		#[pallet::weight({10_000})]
+		#[pallet::weight(T::WeightInfo::deposit_rewards())]
 		pub fn deposit_rewards(
 			origin: OriginFor<T>,
 			amount: BalanceOf<T>,
 		) -> DispatchResult {
-			// TODO: Implement deposit_rewards functionality
-			// 1. Ensure origin is from RewardOrigin and signed
-			// 2. Validate amount > 0
-			// 3. Update pool state
-			// 4. Transfer rewards to pool account
-			// 5. Update total rewards
-			// 6. Update AccRewardPerShare if there are deposits
-			//
-			// Hints:
-			// - Use T::RewardOrigin::ensure_origin(origin.clone())?
-			// - reward_per_share_increase = amount × precision / total_deposited
-			// - Only update AccRewardPerShare if total_deposited > 0
-			todo!()
+			T::RewardOrigin::ensure_origin(origin.clone())?;
+			let who = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			// Without deposits the rewards could not be accounted to anyone and stay in the pot.
+			let total_deposited = TotalDeposited::<T>::get();
+			ensure!(!total_deposited.is_zero(), Error::<T>::NoDeposits);
+
+			Self::update_pool()?;
+			T::Currency::transfer(&who, &Self::account_id(), amount, Preservation::Preserve)?;
+			TotalRewards::<T>::try_mutate(|total| {
+				*total = total.checked_add(&amount).ok_or(Error::<T>::ArithmeticOverflow)?;
+				Ok::<_, DispatchError>(())
+			})?;
+
+			let increase = amount
+				.checked_mul(&Self::precision())
+				.and_then(|scaled| scaled.checked_div(&total_deposited))
+				.ok_or(Error::<T>::ArithmeticOverflow)?;
+			let acc_reward_per_share = AccRewardPerShare::<T>::try_mutate(|acc| {
+				*acc = acc.checked_add(&increase).ok_or(Error::<T>::ArithmeticOverflow)?;
+				Ok::<_, DispatchError>(*acc)
+			})?;
+
+			Self::deposit_event(Event::RewardsDeposited {
+				who,
+				amount,
+				acc_reward_per_share,
+				total_deposited,
+			});
+			Ok(())
 		}
 	}
 
 	impl<T: Config> Pallet<T> {
-		/// The account ID of the pool
+		/// The account ID of the reward pot
 		pub fn account_id() -> T::AccountId {
-			// TODO: Convert PalletId to AccountId
-			// Hint: Use T::PalletId::get().into_account_truncating()
-			todo!()
+			T::PalletId::get().into_account_truncating()
 		}
 
 		/// Precision factor for reward calculations (1e12)
-		fn precision() -> BalanceOf<T> {
-			// TODO: Return 1e12 as BalanceOf<T>
-			// Hint: 1_000_000_000_000
-			todo!()
+		pub(crate) fn precision() -> BalanceOf<T> {
+			1_000_000_000_000u128.saturated_into()
 		}
 
 		/// Update pool state (called before any state-changing operation)
 		fn update_pool() -> DispatchResult {
-			// TODO: Update the last reward block
-			// 1. Get current block number
-			// 2. Update LastRewardBlock storage
-			// Hint: Use frame_system::Pallet::<T>::block_number()
-			todo!()
+			LastRewardBlock::<T>::put(frame_system::Pallet::<T>::block_number());
+			Ok(())
 		}
+
+		/// The part of the reward pot that can be paid out
+		fn reward_pot_balance() -> BalanceOf<T> {
+			T::Currency::reducible_balance(
+				&Self::account_id(),
+				Preservation::Expendable,
+				Fortitude::Polite,
+			)
+		}
+
+		/// Pay `amount` of rewards from the reward pot to `who`
+		fn pay_reward(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+			if amount.is_zero() {
+				return Ok(());
+			}
+			ensure!(Self::reward_pot_balance() >= amount, Error::<T>::InsufficientPoolBalance);
+			T::Currency::transfer(&Self::account_id(), who, amount, Preservation::Expendable)?;
+			Ok(())
+		}
+
+		/// Pay what the reward pot covers of `pending` to `who` and keep the rest in `OwedRewards`,
+		/// returning both parts
+		fn pay_or_owe_reward(
+			who: &T::AccountId,
+			pending: BalanceOf<T>,
+		) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+			let reward_paid = pending.min(Self::reward_pot_balance());
+			let reward_owed = pending.saturating_sub(reward_paid);
+			Self::pay_reward(who, reward_paid)?;
+			if !reward_owed.is_zero() {
+				OwedRewards::<T>::mutate(who, |owed| owed.saturating_accrue(reward_owed));
+			}
+			Ok((reward_paid, reward_owed))
+		}
+
+		/// Rewards already accounted for a deposit of `amount` at the current reward level
+		fn reward_debt(amount: BalanceOf<T>) -> Result<BalanceOf<T>, DispatchError> {
+			amount
+				.checked_mul(&AccRewardPerShare::<T>::get())
+				.map(|scaled| scaled / Self::precision())
+				.ok_or_else(|| Error::<T>::ArithmeticOverflow.into())
+		}
+
 		/// Calculate pending rewards for a user
 		fn calculate_pending_rewards(who: &T::AccountId) -> Result<BalanceOf<T>, DispatchError> {
-			// TODO: Implement reward calculation
-			// 1. Get user's deposit info
-			// 2. Get current AccRewardPerShare
-			// 3. Calculate total rewards user should have: amount × AccRewardPerShare / precision
-			// 4. Calculate pending: total_rewards - reward_debt
-			//
-			// Formula: pending = (amount × AccRewardPerShare / precision) - reward_debt
+			// pending = (amount × AccRewardPerShare / precision) - reward_debt
 			//
 			// This works because:
 			// - total_rewards = what user would earn if they were here from start
 			// - reward_debt = what they would have earned before they joined
 			// - pending = what they actually earned since joining
-			todo!()
+			let deposit = Deposits::<T>::get(who).ok_or(Error::<T>::NoDeposit)?;
+			let total_rewards = Self::reward_debt(deposit.amount)?;
+			Ok(total_rewards.saturating_sub(deposit.reward_debt))
 		}
 
 	}
@@ -1,12 +1,12 @@
-//! Mocks for the vesting module.
+//! Mocks for the native pools module.
 
 #![cfg(test)]
 
 use super::*;
 use polkadot_sdk::{frame_support::{
-	construct_runtime, derive_impl, parameter_types,
-	traits::{ConstU32, ConstU64, EnsureOrigin},
-}, sp_runtime::traits::ConvertInto};
+	construct_runtime, derive_impl, ord_parameter_types, parameter_types,
+	traits::ConstU64,
+}, frame_system::EnsureSignedBy};
 
 use polkadot_sdk::{
 	polkadot_sdk_frame::runtime::prelude::*,
@@ -48,9 +48,21 @@ impl pallet_balances::Config for Runtime {
 }
 
 
+parameter_types! {
+	pub const NativePoolsPalletId: PalletId = PalletId(*b"py/natpl");
+}
+
+ord_parameter_types! {
+	pub const RewardAdmin: AccountId = CHARLIE;
+}
+
 impl Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type Currency = PalletBalances;
-
+	type PalletId = NativePoolsPalletId;
+	type RewardOrigin = EnsureSignedBy<RewardAdmin, AccountId>;
+	type WeightInfo = ();
 }
 
 type Block = frame_system::mocking::MockBlock<Runtime>;
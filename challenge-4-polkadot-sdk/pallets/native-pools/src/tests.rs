@@ -1,9 +1,273 @@
 #![cfg(test)]
 
 use super::*;
-use polkadot_sdk::frame_support::{assert_noop, assert_ok};
+use polkadot_sdk::frame_support::{assert_noop, assert_ok, traits::fungible::InspectHold};
+use polkadot_sdk::sp_runtime::DispatchError;
 use mock::*;
 
+const PRECISION: u64 = 1_000_000_000_000;
 
+fn held(who: AccountId) -> u64 {
+	PalletBalances::balance_on_hold(&HoldReason::PoolDeposit.into(), &who)
+}
 
-// TODO: Add tests for the pallet native pools 
+#[test]
+fn deposit_works() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(NativePools::deposit(RuntimeOrigin::signed(ALICE), 0), Error::<Runtime>::ZeroAmount);
+		assert_noop!(
+			NativePools::deposit(RuntimeOrigin::signed(ALICE), ALICE_BALANCE + 1),
+			Error::<Runtime>::InsufficientBalance
+		);
+
+		assert_ok!(NativePools::deposit(RuntimeOrigin::signed(ALICE), 50));
+		assert_eq!(PalletBalances::free_balance(ALICE), ALICE_BALANCE - 50);
+		assert_eq!(held(ALICE), 50);
+		assert_eq!(PalletBalances::free_balance(NativePools::account_id()), 0);
+		assert_eq!(
+			NativePools::deposits(ALICE),
+			Some(DepositInfo { amount: 50, deposit_block: 1, reward_debt: 0 })
+		);
+		assert_eq!(NativePools::total_deposited(), 50);
+		System::assert_last_event(
+			Event::Deposited {
+				who: ALICE,
+				amount: 50,
+				reward_paid: 0,
+				reward_owed: 0,
+				acc_reward_per_share: 0,
+				total_deposited: 50,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn deposit_rewards_requires_reward_origin() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NativePools::deposit(RuntimeOrigin::signed(ALICE), 50));
+
+		assert_noop!(
+			NativePools::deposit_rewards(RuntimeOrigin::signed(ALICE), 10),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(NativePools::deposit_rewards(RuntimeOrigin::signed(CHARLIE), 10));
+		assert_eq!(NativePools::total_rewards(), 10);
+		assert_eq!(PalletBalances::free_balance(NativePools::account_id()), 10);
+		assert_eq!(NativePools::acc_reward_per_share(), 10 * PRECISION / 50);
+		System::assert_last_event(
+			Event::RewardsDeposited {
+				who: CHARLIE,
+				amount: 10,
+				acc_reward_per_share: 10 * PRECISION / 50,
+				total_deposited: 50,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn deposit_rewards_fails_without_deposits() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			NativePools::deposit_rewards(RuntimeOrigin::signed(CHARLIE), 10),
+			Error::<Runtime>::NoDeposits
+		);
+
+		// Once everyone has withdrawn, the pool is empty again.
+		assert_ok!(NativePools::deposit(RuntimeOrigin::signed(ALICE), 50));
+		assert_ok!(NativePools::withdraw(RuntimeOrigin::signed(ALICE), None));
+		assert_noop!(
+			NativePools::deposit_rewards(RuntimeOrigin::signed(CHARLIE), 10),
+			Error::<Runtime>::NoDeposits
+		);
+		assert_eq!(PalletBalances::free_balance(NativePools::account_id()), 0);
+	});
+}
+
+#[test]
+fn claim_rewards_works() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NativePools::deposit(RuntimeOrigin::signed(ALICE), 50));
+		assert_ok!(NativePools::deposit_rewards(RuntimeOrigin::signed(CHARLIE), 10));
+
+		assert_ok!(NativePools::claim_rewards(RuntimeOrigin::signed(ALICE)));
+		assert_eq!(PalletBalances::free_balance(ALICE), ALICE_BALANCE - 50 + 10);
+		System::assert_last_event(
+			Event::RewardsClaimed {
+				who: ALICE,
+				reward_paid: 10,
+				acc_reward_per_share: 10 * PRECISION / 50,
+				total_deposited: 50,
+			}
+			.into(),
+		);
+
+		assert_noop!(NativePools::claim_rewards(RuntimeOrigin::signed(ALICE)), Error::<Runtime>::ZeroAmount);
+		assert_noop!(NativePools::claim_rewards(RuntimeOrigin::signed(BOB)), Error::<Runtime>::NoDeposit);
+	});
+}
+
+#[test]
+fn withdraw_works() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NativePools::deposit(RuntimeOrigin::signed(ALICE), 50));
+		assert_ok!(NativePools::deposit_rewards(RuntimeOrigin::signed(CHARLIE), 10));
+
+		assert_noop!(
+			NativePools::withdraw(RuntimeOrigin::signed(ALICE), Some(51)),
+			Error::<Runtime>::InsufficientBalance
+		);
+
+		assert_ok!(NativePools::withdraw(RuntimeOrigin::signed(ALICE), Some(20)));
+		assert_eq!(PalletBalances::free_balance(ALICE), ALICE_BALANCE - 50 + 20 + 10);
+		assert_eq!(NativePools::deposits(ALICE).map(|d| d.amount), Some(30));
+		assert_eq!(held(ALICE), 30);
+		System::assert_last_event(
+			Event::Withdrawn {
+				who: ALICE,
+				amount: 20,
+				reward_paid: 10,
+				reward_owed: 0,
+				acc_reward_per_share: 10 * PRECISION / 50,
+				total_deposited: 30,
+			}
+			.into(),
+		);
+
+		assert_ok!(NativePools::withdraw(RuntimeOrigin::signed(ALICE), None));
+		assert_eq!(PalletBalances::free_balance(ALICE), ALICE_BALANCE + 10);
+		assert_eq!(NativePools::deposits(ALICE), None);
+		assert_eq!(held(ALICE), 0);
+		assert_eq!(NativePools::total_deposited(), 0);
+		System::assert_last_event(
+			Event::Withdrawn {
+				who: ALICE,
+				amount: 30,
+				reward_paid: 0,
+				reward_owed: 0,
+				acc_reward_per_share: 10 * PRECISION / 50,
+				total_deposited: 0,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn top_up_pays_out_pending_rewards() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NativePools::deposit(RuntimeOrigin::signed(ALICE), 50));
+		assert_ok!(NativePools::deposit_rewards(RuntimeOrigin::signed(CHARLIE), 10));
+
+		assert_ok!(NativePools::deposit(RuntimeOrigin::signed(ALICE), 25));
+		assert_eq!(PalletBalances::free_balance(ALICE), ALICE_BALANCE - 75 + 10);
+		assert_eq!(NativePools::deposits(ALICE).map(|d| d.reward_debt), Some(15));
+		System::assert_last_event(
+			Event::Deposited {
+				who: ALICE,
+				amount: 25,
+				reward_paid: 10,
+				reward_owed: 0,
+				acc_reward_per_share: 10 * PRECISION / 50,
+				total_deposited: 75,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn reward_pot_shortfall_does_not_touch_deposits() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NativePools::deposit(RuntimeOrigin::signed(ALICE), 50));
+		// Rewards are accounted for, but the pot has been drained.
+		AccRewardPerShare::<Runtime>::put(10 * PRECISION / 50);
+
+		assert_noop!(
+			NativePools::claim_rewards(RuntimeOrigin::signed(ALICE)),
+			Error::<Runtime>::InsufficientPoolBalance
+		);
+		assert_eq!(held(ALICE), 50);
+
+		// The principal is released in full, the reward waits for the pot to be refilled.
+		assert_ok!(NativePools::withdraw(RuntimeOrigin::signed(ALICE), None));
+		assert_eq!(held(ALICE), 0);
+		assert_eq!(PalletBalances::free_balance(ALICE), ALICE_BALANCE);
+		assert_eq!(NativePools::owed_rewards(ALICE), 10);
+		System::assert_last_event(
+			Event::Withdrawn {
+				who: ALICE,
+				amount: 50,
+				reward_paid: 0,
+				reward_owed: 10,
+				acc_reward_per_share: 10 * PRECISION / 50,
+				total_deposited: 0,
+			}
+			.into(),
+		);
+
+		assert_ok!(NativePools::deposit(RuntimeOrigin::signed(CHARLIE), 20));
+		assert_ok!(NativePools::deposit_rewards(RuntimeOrigin::signed(CHARLIE), 10));
+		assert_ok!(NativePools::claim_rewards(RuntimeOrigin::signed(ALICE)));
+		assert_eq!(PalletBalances::free_balance(ALICE), ALICE_BALANCE + 10);
+		assert_eq!(NativePools::owed_rewards(ALICE), 0);
+		assert_noop!(NativePools::claim_rewards(RuntimeOrigin::signed(ALICE)), Error::<Runtime>::NoDeposit);
+	});
+}
+
+#[test]
+fn top_up_with_empty_reward_pot_owes_the_rewards() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NativePools::deposit(RuntimeOrigin::signed(ALICE), 50));
+		// Rewards are accounted for, but the pot is empty.
+		AccRewardPerShare::<Runtime>::put(10 * PRECISION / 50);
+
+		assert_ok!(NativePools::deposit(RuntimeOrigin::signed(ALICE), 25));
+		assert_eq!(held(ALICE), 75);
+		assert_eq!(PalletBalances::free_balance(ALICE), ALICE_BALANCE - 75);
+		assert_eq!(NativePools::owed_rewards(ALICE), 10);
+		assert_eq!(NativePools::deposits(ALICE).map(|d| d.reward_debt), Some(15));
+		System::assert_last_event(
+			Event::Deposited {
+				who: ALICE,
+				amount: 25,
+				reward_paid: 0,
+				reward_owed: 10,
+				acc_reward_per_share: 10 * PRECISION / 50,
+				total_deposited: 75,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn withdraw_pays_what_the_reward_pot_covers() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(NativePools::deposit(RuntimeOrigin::signed(ALICE), 50));
+		assert_ok!(NativePools::deposit_rewards(RuntimeOrigin::signed(CHARLIE), 4));
+		// Another 6 are accounted for without reaching the pot.
+		AccRewardPerShare::<Runtime>::put(10 * PRECISION / 50);
+
+		assert_ok!(NativePools::withdraw(RuntimeOrigin::signed(ALICE), Some(20)));
+		assert_eq!(held(ALICE), 30);
+		assert_eq!(PalletBalances::free_balance(ALICE), ALICE_BALANCE - 30 + 4);
+		assert_eq!(NativePools::owed_rewards(ALICE), 6);
+		assert_eq!(PalletBalances::free_balance(NativePools::account_id()), 0);
+	});
+}
@@ -0,0 +1,299 @@
+
+//! Autogenerated weights for `pallet_native_pools`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 47.0.0
+//! DATE: 2026-10-18, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
+
+// Executed Command:
+// frame-omni-bencher
+// v1
+// benchmark
+// pallet
+// --runtime
+// target/release/wbuild/minimal-template-runtime/minimal_template_runtime.compact.compressed.wasm
+// --pallet
+// pallet_native_pools
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --template
+// .maintain/frame-weight-template.hbs
+// --output
+// pallets/native-pools/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use polkadot_sdk::{frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}}, frame_system};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_native_pools`.
+pub trait WeightInfo {
+	fn deposit_first() -> Weight;
+	fn deposit_top_up() -> Weight;
+	fn withdraw_partial() -> Weight;
+	fn withdraw_full() -> Weight;
+	fn claim_rewards() -> Weight;
+	fn deposit_rewards() -> Weight;
+}
+
+/// Weights for `pallet_native_pools` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::Deposits` (r:1 w:1)
+	/// Proof: `NativePools::Deposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::AccRewardPerShare` (r:1 w:0)
+	/// Proof: `NativePools::AccRewardPerShare` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::TotalDeposited` (r:1 w:1)
+	/// Proof: `NativePools::TotalDeposited` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::LastRewardBlock` (r:0 w:1)
+	/// Proof: `NativePools::LastRewardBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn deposit_first() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3534`
+		// Minimum execution time: 72_714_000 picoseconds.
+		Weight::from_parts(76_228_000, 3534)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::Deposits` (r:1 w:1)
+	/// Proof: `NativePools::Deposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::AccRewardPerShare` (r:1 w:0)
+	/// Proof: `NativePools::AccRewardPerShare` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::TotalDeposited` (r:1 w:1)
+	/// Proof: `NativePools::TotalDeposited` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::LastRewardBlock` (r:0 w:1)
+	/// Proof: `NativePools::LastRewardBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn deposit_top_up() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `306`
+		//  Estimated: `3569`
+		// Minimum execution time: 178_229_000 picoseconds.
+		Weight::from_parts(194_355_000, 3569)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `NativePools::Deposits` (r:1 w:1)
+	/// Proof: `NativePools::Deposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::AccRewardPerShare` (r:1 w:0)
+	/// Proof: `NativePools::AccRewardPerShare` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::TotalDeposited` (r:1 w:1)
+	/// Proof: `NativePools::TotalDeposited` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::LastRewardBlock` (r:0 w:1)
+	/// Proof: `NativePools::LastRewardBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn withdraw_partial() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `306`
+		//  Estimated: `3569`
+		// Minimum execution time: 124_502_000 picoseconds.
+		Weight::from_parts(127_878_000, 3569)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `NativePools::Deposits` (r:1 w:1)
+	/// Proof: `NativePools::Deposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::AccRewardPerShare` (r:1 w:0)
+	/// Proof: `NativePools::AccRewardPerShare` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::TotalDeposited` (r:1 w:1)
+	/// Proof: `NativePools::TotalDeposited` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::LastRewardBlock` (r:0 w:1)
+	/// Proof: `NativePools::LastRewardBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn withdraw_full() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `306`
+		//  Estimated: `3569`
+		// Minimum execution time: 118_619_000 picoseconds.
+		Weight::from_parts(124_624_000, 3569)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `NativePools::Deposits` (r:1 w:1)
+	/// Proof: `NativePools::Deposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::OwedRewards` (r:1 w:1)
+	/// Proof: `NativePools::OwedRewards` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::AccRewardPerShare` (r:1 w:0)
+	/// Proof: `NativePools::AccRewardPerShare` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::TotalDeposited` (r:1 w:0)
+	/// Proof: `NativePools::TotalDeposited` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::LastRewardBlock` (r:0 w:1)
+	/// Proof: `NativePools::LastRewardBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn claim_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `258`
+		//  Estimated: `3569`
+		// Minimum execution time: 87_987_000 picoseconds.
+		Weight::from_parts(93_182_000, 3569)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `NativePools::TotalDeposited` (r:1 w:0)
+	/// Proof: `NativePools::TotalDeposited` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::TotalRewards` (r:1 w:1)
+	/// Proof: `NativePools::TotalRewards` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::AccRewardPerShare` (r:1 w:1)
+	/// Proof: `NativePools::AccRewardPerShare` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::LastRewardBlock` (r:0 w:1)
+	/// Proof: `NativePools::LastRewardBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn deposit_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351`
+		//  Estimated: `6148`
+		// Minimum execution time: 76_589_000 picoseconds.
+		Weight::from_parts(79_185_000, 6148)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::Deposits` (r:1 w:1)
+	/// Proof: `NativePools::Deposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::AccRewardPerShare` (r:1 w:0)
+	/// Proof: `NativePools::AccRewardPerShare` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::TotalDeposited` (r:1 w:1)
+	/// Proof: `NativePools::TotalDeposited` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::LastRewardBlock` (r:0 w:1)
+	/// Proof: `NativePools::LastRewardBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn deposit_first() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3534`
+		// Minimum execution time: 72_714_000 picoseconds.
+		Weight::from_parts(76_228_000, 3534)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::Deposits` (r:1 w:1)
+	/// Proof: `NativePools::Deposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::AccRewardPerShare` (r:1 w:0)
+	/// Proof: `NativePools::AccRewardPerShare` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::TotalDeposited` (r:1 w:1)
+	/// Proof: `NativePools::TotalDeposited` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::LastRewardBlock` (r:0 w:1)
+	/// Proof: `NativePools::LastRewardBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn deposit_top_up() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `306`
+		//  Estimated: `3569`
+		// Minimum execution time: 178_229_000 picoseconds.
+		Weight::from_parts(194_355_000, 3569)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `NativePools::Deposits` (r:1 w:1)
+	/// Proof: `NativePools::Deposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::AccRewardPerShare` (r:1 w:0)
+	/// Proof: `NativePools::AccRewardPerShare` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::TotalDeposited` (r:1 w:1)
+	/// Proof: `NativePools::TotalDeposited` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::LastRewardBlock` (r:0 w:1)
+	/// Proof: `NativePools::LastRewardBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn withdraw_partial() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `306`
+		//  Estimated: `3569`
+		// Minimum execution time: 124_502_000 picoseconds.
+		Weight::from_parts(127_878_000, 3569)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `NativePools::Deposits` (r:1 w:1)
+	/// Proof: `NativePools::Deposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::AccRewardPerShare` (r:1 w:0)
+	/// Proof: `NativePools::AccRewardPerShare` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::TotalDeposited` (r:1 w:1)
+	/// Proof: `NativePools::TotalDeposited` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::LastRewardBlock` (r:0 w:1)
+	/// Proof: `NativePools::LastRewardBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn withdraw_full() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `306`
+		//  Estimated: `3569`
+		// Minimum execution time: 118_619_000 picoseconds.
+		Weight::from_parts(124_624_000, 3569)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `NativePools::Deposits` (r:1 w:1)
+	/// Proof: `NativePools::Deposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::OwedRewards` (r:1 w:1)
+	/// Proof: `NativePools::OwedRewards` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::AccRewardPerShare` (r:1 w:0)
+	/// Proof: `NativePools::AccRewardPerShare` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::TotalDeposited` (r:1 w:0)
+	/// Proof: `NativePools::TotalDeposited` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::LastRewardBlock` (r:0 w:1)
+	/// Proof: `NativePools::LastRewardBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn claim_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `258`
+		//  Estimated: `3569`
+		// Minimum execution time: 87_987_000 picoseconds.
+		Weight::from_parts(93_182_000, 3569)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `NativePools::TotalDeposited` (r:1 w:0)
+	/// Proof: `NativePools::TotalDeposited` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::TotalRewards` (r:1 w:1)
+	/// Proof: `NativePools::TotalRewards` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::AccRewardPerShare` (r:1 w:1)
+	/// Proof: `NativePools::AccRewardPerShare` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NativePools::LastRewardBlock` (r:0 w:1)
+	/// Proof: `NativePools::LastRewardBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn deposit_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351`
+		//  Estimated: `6148`
+		// Minimum execution time: 76_589_000 picoseconds.
+		Weight::from_parts(79_185_000, 6148)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+}
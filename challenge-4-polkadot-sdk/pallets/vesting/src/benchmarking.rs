@@ -0,0 +1,579 @@
+//! Benchmarking setup for pallet-vesting
+
+use super::*;
+use frame::benchmarking::prelude::*;
+use frame_system::RawOrigin;
+use polkadot_sdk::frame_support::traits::{fungible::InspectHold as _, fungibles::Mutate as _};
+
+const SEED: u32 = 0;
+
+/// The amount locked by every schedule the benchmarks create
+fn schedule_amount<T: Config>() -> BalanceOf<T> {
+	T::MinVestedTransfer::get()
+		.max(T::Currency::minimum_balance())
+		.saturating_mul(100u32.into())
+}
+
+/// A revocable schedule from `grantor` that starts next block and vests linearly over 100 blocks
+fn linear_schedule<T: Config>(grantor: &T::AccountId) -> VestingScheduleOf<T> {
+	VestingSchedule {
+		start: T::BlockNumberProvider::current_block_number().saturating_add(One::one()),
+		curve: VestingCurve::Linear { duration: 100u32.into(), total: schedule_amount::<T>() },
+		grantor: Some(grantor.clone()),
+		revocable: true,
+		tge_relative: false,
+		released_early: Zero::zero(),
+		deposit: Zero::zero(),
+	}
+}
+
+/// Fund `who` and give it `count` schedules granted by `grantor`
+fn add_schedules<T: Config>(
+	who: &T::AccountId,
+	grantor: &T::AccountId,
+	count: u32,
+) -> Result<(), BenchmarkError> {
+	let amount = schedule_amount::<T>().saturating_mul(count.into());
+	T::Currency::set_balance(who, T::Currency::minimum_balance().saturating_add(amount));
+
+	let schedules: Vec<_> = (0..count).map(|_| linear_schedule::<T>(grantor)).collect();
+	let schedules = BoundedVec::try_from(schedules)
+		.map_err(|_| BenchmarkError::Stop("Too many vesting schedules"))?;
+	VestingSchedules::<T>::insert(who, schedules);
+	Pallet::<T>::update_lock(who)?;
+	Ok(())
+}
+
+/// Hold `ScheduleDeposit` from `grantor` for each schedule of `who`
+fn hold_deposits<T: Config>(who: &T::AccountId, grantor: &T::AccountId) -> Result<(), BenchmarkError> {
+	let deposit = T::ScheduleDeposit::get();
+	let count = VestingSchedules::<T>::decode_len(who).unwrap_or_default() as u32;
+	T::Currency::set_balance(
+		grantor,
+		T::Currency::minimum_balance().saturating_add(deposit.saturating_mul(count.into())),
+	);
+	VestingSchedules::<T>::try_mutate(who, |schedules| {
+		for schedule in schedules.iter_mut() {
+			schedule.deposit = Pallet::<T>::hold_deposit(grantor)?;
+		}
+		Ok::<_, DispatchError>(())
+	})?;
+	Ok(())
+}
+
+/// Fund `who` in `asset` and give it `count` schedules granted by `grantor`
+fn add_asset_schedules<T: Config>(
+	asset: T::AssetId,
+	who: &T::AccountId,
+	grantor: &T::AccountId,
+	count: u32,
+) -> Result<(), BenchmarkError> {
+	if count.is_zero() {
+		return Ok(())
+	}
+	T::Assets::set_balance(asset, who, schedule_amount::<T>().saturating_mul(count.into()));
+
+	// Asset schedules cannot be revoked.
+	let schedules: Vec<_> = (0..count)
+		.map(|_| VestingSchedule { revocable: false, ..linear_schedule::<T>(grantor) })
+		.collect();
+	let schedules = BoundedVec::try_from(schedules)
+		.map_err(|_| BenchmarkError::Stop("Too many vesting schedules"))?;
+	AssetVestingSchedules::<T>::insert(asset, who, schedules);
+	VestingAssets::<T>::try_mutate(who, |assets| {
+		if !assets.contains(&asset) {
+			assets.try_push(asset)?;
+		}
+		Ok::<_, T::AssetId>(())
+	})
+	.map_err(|_| BenchmarkError::Stop("Too many vesting assets"))?;
+	Pallet::<T>::update_asset_lock(asset, who)?;
+	Ok(())
+}
+
+/// Fill the accepted grantors of `who` up to `MaxAcceptedGrantors`, with `grantor` last so that
+/// finding it scans the whole list
+fn accept_grantors<T: Config>(who: &T::AccountId, grantor: &T::AccountId) {
+	let max = T::MaxAcceptedGrantors::get();
+	if max.is_zero() {
+		return
+	}
+	let mut grantors: Vec<T::AccountId> =
+		(1..max).map(|i| account("accepted", i, SEED)).collect();
+	grantors.push(grantor.clone());
+	AcceptedGrantors::<T>::insert(who, BoundedVec::truncate_from(grantors));
+}
+
+/// Leave block zero, which the benchmark harness moves to block one before the call, so that
+/// schedules starting next block still start in the future
+fn skip_genesis<T: Config>() {
+	if T::BlockNumberProvider::current_block_number().is_zero() {
+		T::BlockNumberProvider::set_block_number(One::one());
+	}
+}
+
+/// Move to the middle of the schedules created by [`add_schedules`]
+fn halfway<T: Config>() {
+	let now = T::BlockNumberProvider::current_block_number();
+	T::BlockNumberProvider::set_block_number(now.saturating_add(51u32.into()));
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn vested_transfer(
+		s: Linear<0, { T::MaxVestingSchedules::get().saturating_sub(1) }>,
+	) -> Result<(), BenchmarkError> {
+		skip_genesis::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+		let target: T::AccountId = account("target", 0, SEED);
+		add_schedules::<T>(&target, &caller, s)?;
+		accept_grantors::<T>(&target, &caller);
+		T::Currency::set_balance(
+			&caller,
+			T::Currency::minimum_balance()
+				.saturating_add(schedule_amount::<T>())
+				.saturating_add(T::ScheduleDeposit::get()),
+		);
+		let schedule = linear_schedule::<T>(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), target.clone(), schedule);
+
+		assert_eq!(VestingSchedules::<T>::get(&target).len() as u32, s + 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn claim(s: Linear<1, { T::MaxVestingSchedules::get() }>) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let grantor: T::AccountId = account("grantor", 0, SEED);
+		add_schedules::<T>(&caller, &grantor, s)?;
+		hold_deposits::<T>(&caller, &grantor)?;
+		// Every schedule has ended, so all of them are removed and their deposits released.
+		let end = linear_schedule::<T>(&grantor).end().ok_or(BenchmarkError::Weightless)?;
+		T::BlockNumberProvider::set_block_number(end);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert!(VestingSchedules::<T>::get(&caller).is_empty());
+		assert!(T::Currency::balance_on_hold(&HoldReason::ScheduleDeposit.into(), &grantor).is_zero());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn update_vesting_schedules(
+		s: Linear<1, { T::MaxVestingSchedules::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let target: T::AccountId = account("target", 0, SEED);
+		let grantor: T::AccountId = account("grantor", 0, SEED);
+		add_schedules::<T>(&target, &grantor, s)?;
+		let schedules: Vec<_> = (0..s).map(|_| linear_schedule::<T>(&grantor)).collect();
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, target.clone(), schedules);
+
+		assert_eq!(VestingSchedules::<T>::get(&target).len() as u32, s);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn update_vesting_schedule(
+		s: Linear<1, { T::MaxVestingSchedules::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let target: T::AccountId = account("target", 0, SEED);
+		let grantor: T::AccountId = account("grantor", 0, SEED);
+		add_schedules::<T>(&target, &grantor, s)?;
+		let mut schedule = linear_schedule::<T>(&grantor);
+		schedule.revocable = false;
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, target.clone(), s - 1, schedule.clone());
+
+		assert_eq!(VestingSchedules::<T>::get(&target).last(), Some(&schedule));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn force_remove_vesting_schedule(
+		s: Linear<1, { T::MaxVestingSchedules::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let target: T::AccountId = account("target", 0, SEED);
+		let grantor: T::AccountId = account("grantor", 0, SEED);
+		add_schedules::<T>(&target, &grantor, s)?;
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, target.clone(), s - 1);
+
+		assert_eq!(VestingSchedules::<T>::get(&target).len() as u32, s - 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn merge_schedules(
+		s: Linear<2, { T::MaxVestingSchedules::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let grantor: T::AccountId = account("grantor", 0, SEED);
+		add_schedules::<T>(&caller, &grantor, s)?;
+		halfway::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), 0, s - 1);
+
+		assert_eq!(VestingSchedules::<T>::get(&caller).len() as u32, s - 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn split_schedule(
+		s: Linear<1, { T::MaxVestingSchedules::get().saturating_sub(1) }>,
+	) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let grantor: T::AccountId = account("grantor", 0, SEED);
+		add_schedules::<T>(&caller, &grantor, s)?;
+		let amount = schedule_amount::<T>() / 2u32.into();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), 0, amount);
+
+		assert_eq!(VestingSchedules::<T>::get(&caller).len() as u32, s + 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke(s: Linear<1, { T::MaxVestingSchedules::get() }>) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let target: T::AccountId = account("target", 0, SEED);
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		add_schedules::<T>(&target, &caller, s)?;
+		T::Currency::set_balance(&beneficiary, T::Currency::minimum_balance());
+		RevokeBeneficiary::<T>::insert(&caller, &beneficiary);
+		halfway::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), target.clone(), s - 1);
+
+		assert_eq!(VestingSchedules::<T>::get(&target).len() as u32, s - 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_revoke_beneficiary() {
+		let caller: T::AccountId = whitelisted_caller();
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), Some(beneficiary.clone()));
+
+		assert_eq!(RevokeBeneficiary::<T>::get(&caller), Some(beneficiary));
+	}
+
+	#[benchmark]
+	fn transfer_schedule(
+		s: Linear<1, { T::MaxVestingSchedules::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let new_owner: T::AccountId = account("new_owner", 0, SEED);
+		let grantor: T::AccountId = account("grantor", 0, SEED);
+		add_schedules::<T>(&caller, &grantor, s)?;
+		add_schedules::<T>(&new_owner, &grantor, s - 1)?;
+		accept_grantors::<T>(&new_owner, &caller);
+		halfway::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), s - 1, new_owner.clone());
+
+		assert_eq!(VestingSchedules::<T>::get(&new_owner).len() as u32, s);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn release_milestone(
+		s: Linear<1, { T::MaxVestingSchedules::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let target: T::AccountId = account("target", 0, SEED);
+		let grantor: T::AccountId = account("grantor", 0, SEED);
+		add_schedules::<T>(&target, &grantor, s)?;
+		VestingSchedules::<T>::mutate(&target, |schedules| {
+			if let Some(schedule) = schedules.last_mut() {
+				schedule.curve = VestingCurve::Milestones {
+					milestone_count: 2,
+					total: schedule_amount::<T>(),
+					released: BoundedVec::new(),
+				};
+			}
+		});
+		let origin = T::MilestoneOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, target.clone(), s - 1, 0);
+
+		assert_eq!(
+			VestingSchedules::<T>::get(&target).last().map(|schedule| schedule.curve.clone()),
+			Some(VestingCurve::Milestones {
+				milestone_count: 2,
+				total: schedule_amount::<T>(),
+				released: BoundedVec::truncate_from(Vec::from([0])),
+			})
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn batch_vested_transfer(
+		n: Linear<1, { T::MaxBatchSize::get() }>,
+	) -> Result<(), BenchmarkError> {
+		skip_genesis::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+		let schedules = T::MaxVestingSchedules::get().saturating_sub(1);
+		let mut grants = Vec::new();
+		for i in 0..n {
+			let target: T::AccountId = account("target", i, SEED);
+			add_schedules::<T>(&target, &caller, schedules)?;
+			accept_grantors::<T>(&target, &caller);
+			grants.push((target, linear_schedule::<T>(&caller)));
+		}
+		T::Currency::set_balance(
+			&caller,
+			T::Currency::minimum_balance().saturating_add(
+				schedule_amount::<T>()
+					.saturating_add(T::ScheduleDeposit::get())
+					.saturating_mul(n.into()),
+			),
+		);
+		let grants = BoundedVec::try_from(grants)
+			.map_err(|_| BenchmarkError::Stop("Too many grants"))?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), grants);
+
+		let last: T::AccountId = account("target", n - 1, SEED);
+		assert_eq!(VestingSchedules::<T>::get(&last).len() as u32, schedules + 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_accepted_grantors(g: Linear<0, { T::MaxAcceptedGrantors::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let grantors: Vec<T::AccountId> = (0..g).map(|i| account("grantor", i, SEED)).collect();
+		let grantors = BoundedVec::truncate_from(grantors);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), Some(grantors.clone()));
+
+		assert_eq!(AcceptedGrantors::<T>::get(&caller), Some(grantors));
+	}
+
+	#[benchmark]
+	fn freeze_vesting() -> Result<(), BenchmarkError> {
+		let target: T::AccountId = account("target", 0, SEED);
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, target.clone());
+
+		assert!(FrozenSince::<T>::contains_key(&target));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unfreeze_vesting(
+		s: Linear<1, { T::MaxVestingSchedules::get() }>,
+		a: Linear<0, { T::MaxVestingAssets::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let target: T::AccountId = account("target", 0, SEED);
+		let grantor: T::AccountId = account("grantor", 0, SEED);
+		add_schedules::<T>(&target, &grantor, s)?;
+		for i in 0..a {
+			let asset = T::BenchmarkHelper::create_asset(i);
+			add_asset_schedules::<T>(asset, &target, &grantor, T::MaxVestingSchedules::get())?;
+		}
+		FrozenSince::<T>::insert(&target, T::BlockNumberProvider::current_block_number());
+		halfway::<T>();
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, target.clone());
+
+		assert!(!FrozenSince::<T>::contains_key(&target));
+		assert_eq!(VestingAssets::<T>::get(&target).len() as u32, a);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn vested_transfer_asset(
+		s: Linear<0, { T::MaxVestingSchedules::get().saturating_sub(1) }>,
+	) -> Result<(), BenchmarkError> {
+		skip_genesis::<T>();
+		let asset = T::BenchmarkHelper::create_asset(0);
+		let caller: T::AccountId = whitelisted_caller();
+		let target: T::AccountId = account("target", 0, SEED);
+		add_asset_schedules::<T>(asset, &target, &caller, s)?;
+		accept_grantors::<T>(&target, &caller);
+		T::Assets::set_balance(asset, &caller, schedule_amount::<T>().saturating_mul(2u32.into()));
+		T::Currency::set_balance(
+			&caller,
+			T::Currency::minimum_balance().saturating_add(T::ScheduleDeposit::get()),
+		);
+		// Asset schedules cannot be revoked.
+		let schedule = VestingSchedule { revocable: false, ..linear_schedule::<T>(&caller) };
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), asset, target.clone(), schedule);
+
+		assert_eq!(AssetVestingSchedules::<T>::get(asset, &target).len() as u32, s + 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn claim_asset(s: Linear<1, { T::MaxVestingSchedules::get() }>) -> Result<(), BenchmarkError> {
+		let asset = T::BenchmarkHelper::create_asset(0);
+		let caller: T::AccountId = whitelisted_caller();
+		let grantor: T::AccountId = account("grantor", 0, SEED);
+		add_asset_schedules::<T>(asset, &caller, &grantor, s)?;
+		halfway::<T>();
+		let locked = Pallet::<T>::asset_locked_balance(asset, &caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), asset);
+
+		assert_eq!(
+			<T::AssetsFreezer as fungibles::InspectFreeze<_>>::balance_frozen(
+				asset,
+				&FreezeReason::Vesting.into(),
+				&caller,
+			),
+			locked
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_asset_min_vested_transfer() -> Result<(), BenchmarkError> {
+		let asset = T::BenchmarkHelper::create_asset(0);
+		let amount = schedule_amount::<T>();
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, asset, Some(amount));
+
+		assert_eq!(AssetMinVestedTransfer::<T>::get(asset), Some(amount));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_tge_block() -> Result<(), BenchmarkError> {
+		let block = T::BlockNumberProvider::current_block_number().saturating_add(One::one());
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, block);
+
+		assert_eq!(TgeBlock::<T>::get(), Some(block));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn accelerate_vesting(
+		s: Linear<1, { T::MaxVestingSchedules::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let target: T::AccountId = account("target", 0, SEED);
+		let grantor: T::AccountId = account("grantor", 0, SEED);
+		add_schedules::<T>(&target, &grantor, s)?;
+		halfway::<T>();
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, target.clone(), Acceleration::SpeedUp(2));
+
+		assert!(VestingAccelerations::<T>::contains_key(&target, 0));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cleanup_step() {
+		// The first entry of either index is read, and is not due yet.
+		skip_genesis::<T>();
+		let who: T::AccountId = account("target", 0, SEED);
+		let now = T::BlockNumberProvider::current_block_number();
+		let end = schedule_end_key(now.saturating_add(One::one()));
+		ScheduleEnds::<T>::insert(end, &who, ());
+		TgeBlock::<T>::put(now);
+		TgeScheduleEnds::<T>::insert(end, &who, ());
+
+		#[block]
+		{
+			Pallet::<T>::cleanup_completed(Weight::MAX);
+		}
+
+		assert!(ScheduleEnds::<T>::contains_key(end, &who));
+	}
+
+	#[benchmark]
+	fn cleanup_account(
+		s: Linear<1, { T::MaxVestingSchedules::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let who: T::AccountId = account("target", 0, SEED);
+		let grantor: T::AccountId = account("grantor", 0, SEED);
+		add_schedules::<T>(&who, &grantor, s)?;
+		let end = linear_schedule::<T>(&grantor).end().ok_or(BenchmarkError::Weightless)?;
+		T::BlockNumberProvider::set_block_number(end);
+
+		#[block]
+		{
+			Pallet::<T>::prune_completed(&who, end);
+		}
+
+		assert!(VestingSchedules::<T>::get(&who).is_empty());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn migrate_v1_to_v2_step(s: Linear<1, { T::MaxVestingSchedules::get() }>) {
+		use migrations::v2::{old, LazyMigrationV1ToV2, OldVestingSchedule};
+		use polkadot_sdk::frame_support::migrations::SteppedMigration;
+
+		// Genesis schedules are already in the new layout, so only keep the account to migrate.
+		let _ = VestingSchedules::<T>::clear(u32::MAX, None);
+		let who: T::AccountId = account("target", 0, SEED);
+		let schedule = OldVestingSchedule {
+			start: T::BlockNumberProvider::current_block_number().saturating_add(One::one()),
+			period: One::one(),
+			period_count: 100,
+			per_period: schedule_amount::<T>(),
+		};
+		old::VestingSchedules::<T>::insert(
+			&who,
+			BoundedVec::truncate_from((0..s).map(|_| schedule.clone()).collect()),
+		);
+		StorageVersion::new(1).put::<Pallet<T>>();
+		let mut meter = WeightMeter::with_limit(
+			T::WeightInfo::migrate_v1_to_v2_step(T::MaxVestingSchedules::get()),
+		);
+
+		#[block]
+		{
+			LazyMigrationV1ToV2::<T>::step(None, &mut meter).expect("The step has enough weight");
+		}
+
+		assert_eq!(VestingSchedules::<T>::get(&who).len() as u32, s);
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::ExtBuilder::build(), crate::mock::Runtime);
+}
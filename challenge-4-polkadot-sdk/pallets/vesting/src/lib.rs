@@ -2,14 +2,31 @@
 //!
 //! This pallet allows for creating, managing, and claiming from vesting schedules.
 //! It supports both time-based and block-based vesting mechanisms.
+//!
+//! Schedules are measured in the units of `Config::BlockNumberProvider`: local blocks with
+//! `frame_system`, relay chain blocks with a relay chain block number provider, or milliseconds
+//! with [`UnixTimeProvider`].
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame::prelude::*;
 use polkadot_sdk::polkadot_sdk_frame as frame;
-use polkadot_sdk::frame_support::{
-	traits::{Currency, LockableCurrency, LockIdentifier, WithdrawReasons, ExistenceRequirement}
+use polkadot_sdk::frame_support::traits::{
+	fungible::{Inspect, InspectFreeze, Mutate, MutateFreeze, MutateHold},
+	fungibles,
+	tokens::{Fortitude, Precision, Preservation},
+	LockIdentifier, StorageVersion, UnixTime,
+};
+use polkadot_sdk::frame_support::{defensive, weights::WeightMeter, PalletError};
+use polkadot_sdk::sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{
+		BlockNumberProvider, Bounded, MaybeSerializeDeserialize, One, Saturating,
+		UniqueSaturatedInto, Zero,
+	},
+	Percent, Rounding, SaturatedConversion,
 };
+use serde::{Deserialize, Serialize};
 use polkadot_sdk::sp_std::{
 	cmp::PartialEq,
 	vec::Vec,
@@ -18,79 +35,540 @@ use polkadot_sdk::sp_std::{
 // Re-export all pallet parts, this is needed to properly import the pallet into the runtime.
 pub use pallet::*;
 
+/// The identifier of the `LockableCurrency` lock used before vesting moved to freezes.
 pub const VESTING_ID: LockIdentifier = *b"vesting ";
 
+/// The in-code storage version.
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 type BalanceOf<T> =
-	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// The unit schedules are measured in, as counted by `Config::BlockNumberProvider`.
+pub type BlockNumberFor<T> =
+	<<T as Config>::BlockNumberProvider as BlockNumberProvider>::BlockNumber;
 
-type BlockNumberFor<T> = frame_system::pallet_prelude::BlockNumberFor<T>;
+type VestingScheduleOf<T> =
+	VestingSchedule<<T as frame_system::Config>::AccountId, BlockNumberFor<T>, BalanceOf<T>>;
 
-type VestingScheduleOf<T> = VestingSchedule<BlockNumberFor<T>, BalanceOf<T>>;
+type UnlockCalendarOf<T> =
+	BoundedVec<(BlockNumberFor<T>, BalanceOf<T>, BalanceOf<T>), MaxCalendarEntries>;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod migrations;
 mod mock;
+pub mod runtime_api;
+pub mod weights;
+pub use weights::WeightInfo;
 mod tests;
 
+/// A [`BlockNumberProvider`] counting the milliseconds of a [`UnixTime`] source, so schedules
+/// can be written in wall-clock time.
+pub struct UnixTimeProvider<Time>(PhantomData<Time>);
+
+impl<Time: UnixTime> BlockNumberProvider for UnixTimeProvider<Time> {
+	type BlockNumber = u64;
+
+	fn current_block_number() -> Self::BlockNumber {
+		Time::now().as_millis().saturated_into()
+	}
+}
+
+/// Provides an asset for the benchmarks of `vested_transfer_asset` and `claim_asset`.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId> {
+	/// Create the `index`th of the distinct assets that accounts can be funded in with
+	/// `fungibles::Mutate::set_balance`
+	fn create_asset(index: u32) -> AssetId;
+}
+
+/// The maximum number of tranches in a [`VestingCurve::Tranches`] schedule.
+pub type MaxTranches = ConstU32<16>;
+
+/// The maximum number of milestones in a [`VestingCurve::Milestones`] schedule.
+pub type MaxMilestones = ConstU32<16>;
+
+/// How the amount of a [`VestingSchedule`] is released after its `start`.
+#[derive(
+	Clone, Encode, Decode, MaxEncodedLen, TypeInfo, Debug, PartialEq, Eq, DecodeWithMemTracking,
+	Serialize, Deserialize,
+)]
+pub enum VestingCurve<BlockNumber, Balance> {
+	/// `per_period` is released every `period` blocks, `period_count` times.
+	Stepped {
+		/// The number of blocks between vesting periods
+		period: BlockNumber,
+		/// The number of vesting periods
+		period_count: u32,
+		/// The amount of balance that will be vested per period
+		per_period: Balance,
+	},
+	/// Nothing is released for `cliff` blocks, then everything accrued linearly since `start` is
+	/// released at once and the rest follows block by block until `duration` has passed.
+	Cliff {
+		/// The number of blocks before anything is released
+		cliff: BlockNumber,
+		/// The number of blocks until everything is released
+		duration: BlockNumber,
+		/// The amount of balance that will be vested
+		total: Balance,
+	},
+	/// `total` is released block by block over `duration` blocks.
+	Linear {
+		/// The number of blocks until everything is released
+		duration: BlockNumber,
+		/// The amount of balance that will be vested
+		total: Balance,
+	},
+	/// Each `(offset, amount)` tranche is released `offset` blocks after `start`. Offsets must be
+	/// strictly increasing.
+	Tranches(BoundedVec<(BlockNumber, Balance), MaxTranches>),
+	/// Nothing is released until `MilestoneOrigin` releases a milestone with
+	/// `release_milestone`, and each released milestone unlocks an equal share of `total`.
+	Milestones {
+		/// The number of milestones, identified by `0..milestone_count`
+		milestone_count: u32,
+		/// The amount of balance that will be vested
+		total: Balance,
+		/// The milestones released so far, in increasing order
+		released: BoundedVec<u32, MaxMilestones>,
+	},
+}
+
 /// A vesting schedule over a currency. This allows a particular currency to have vesting limits
 /// applied to it.
-#[derive(Clone, Encode, Decode, MaxEncodedLen, TypeInfo, Debug, PartialEq, DecodeWithMemTracking)]
-pub struct VestingSchedule<BlockNumber, Balance> {
-	/// The block number when the vesting schedule starts
+#[derive(
+	Clone, Encode, Decode, MaxEncodedLen, TypeInfo, Debug, PartialEq, Eq, DecodeWithMemTracking,
+	Serialize, Deserialize,
+)]
+pub struct VestingSchedule<AccountId, BlockNumber, Balance> {
+	/// The block number when the vesting schedule starts, or its offset from the `TgeBlock` if
+	/// `tge_relative`
 	pub start: BlockNumber,
-	/// The number of blocks between vesting periods
-	pub period: BlockNumber,
-	/// The number of vesting periods
-	pub period_count: u32,
-	/// The amount of balance that will be vested per period
-	pub per_period: Balance,
+	/// How the vested amount is released over time
+	pub curve: VestingCurve<BlockNumber, Balance>,
+	/// The account that funded the schedule, set to the sender by `vested_transfer`
+	pub grantor: Option<AccountId>,
+	/// Whether the grantor can take back the part that has not vested yet
+	pub revocable: bool,
+	/// Whether `start` counts from the `TgeBlock`, keeping everything locked until it is set
+	pub tge_relative: bool,
+	/// The part of the total released at once by `accelerate_vesting`, the curve releases the
+	/// rest in proportion
+	pub released_early: Balance,
+	/// The storage deposit held from the `grantor` for this schedule, returned when it is removed
+	pub deposit: Balance,
 }
 
 
-impl<BlockNumber: AtLeast32Bit + Copy, Balance: AtLeast32Bit + MaxEncodedLen + Copy>
-	VestingSchedule<BlockNumber, Balance>
+impl<
+		AccountId: Clone,
+		BlockNumber: AtLeast32Bit + Copy,
+		Balance: AtLeast32Bit + MaxEncodedLen + Copy,
+	> VestingSchedule<AccountId, BlockNumber, Balance>
 {
 	/// Returns the total amount to be vested
 	pub fn total_amount(&self) -> Option<Balance> {
-		// TODO: Calculate the total amount by multiplying per_period by period_count
-		// Hint: Use checked_mul to avoid overflow and return None if overflow occurs
-		todo!()
+		match &self.curve {
+			VestingCurve::Stepped { period_count, per_period, .. } =>
+				per_period.checked_mul(&(*period_count).into()),
+			VestingCurve::Cliff { total, .. } |
+			VestingCurve::Linear { total, .. } |
+			VestingCurve::Milestones { total, .. } => Some(*total),
+			VestingCurve::Tranches(tranches) => tranches
+				.iter()
+				.try_fold(Balance::zero(), |total, (_, amount)| total.checked_add(amount)),
+		}
 	}
 
-	/// Linear vesting schedule
-	// start = 100
-	// period = 10 (every 10 blocks)
-	// period_count = 5 (5 periods total)
-	// per_period = 1000 tokens
-
-	// At block 120:
-	// elapsed_blocks = 120 - 100 = 20
-	// elapsed_periods = min(20/10, 5) = min(2, 5) = 2
-	// vested_amount = 2 × 1000 = 2000 tokens
-	
 	/// Returns the vested amount at the given block
+	///
+	/// Nothing vests before `start`, and a TGE-relative schedule vests nothing until it is
+	/// anchored. After that, with `elapsed = now - start`, each curve has vested:
+	///
+	/// - `Stepped`: `min(elapsed / period, period_count) * per_period`. With `start = 100`,
+	///   `period = 10`, `period_count = 5` and `per_period = 1000`, block 120 has vested
+	///   `min(20 / 10, 5) * 1000 = 2000`.
+	/// - `Cliff`: nothing while `elapsed < cliff`, then `total * elapsed / duration`, capped at
+	///   `total`.
+	/// - `Linear`: `total * elapsed / duration`, capped at `total`.
+	/// - `Tranches`: the sum of the tranches whose offset is at most `elapsed`.
+	/// - `Milestones`: `total * released / milestone_count`, however much time has passed.
+	///
+	/// What `accelerate_vesting` released early is vested at any block, and the curve then
+	/// releases the rest of the total in proportion.
 	pub fn vested_amount<BlockNumberToBalance: Convert<BlockNumber, Balance>>(&self, now: BlockNumber) -> Balance {
-		// TODO: Implement vested amount calculation
-		// 1. If vesting hasn't started (now < self.start), return zero
-		// 2. Calculate elapsed blocks since start
-		// 3. Calculate how many complete periods have passed (elapsed / period)
-		// 4. Cap the periods at period_count
-		// 5. Multiply periods by per_period amount
-		// Hint: Use saturating operations to avoid overflow
-		todo!()
+		let total = self.total_amount().unwrap_or_else(Bounded::max_value);
+		let released_early = self.released_early.min(total);
+		if self.tge_relative || now < self.start {
+			return released_early;
+		}
+		let vested = self.curve_vested::<BlockNumberToBalance>(now.saturating_sub(self.start));
+		if released_early.is_zero() {
+			return vested;
+		}
+		// The curve only releases what was not released early, in proportion.
+		released_early.saturating_add(Self::fraction_of(
+			vested,
+			total.saturating_sub(released_early).saturated_into(),
+			total.saturated_into(),
+		))
+	}
+
+	/// Returns the amount the curve alone has vested `elapsed` blocks after `start`
+	fn curve_vested<BlockNumberToBalance: Convert<BlockNumber, Balance>>(
+		&self,
+		elapsed: BlockNumber,
+	) -> Balance {
+		match &self.curve {
+			VestingCurve::Stepped { period, period_count, per_period } => {
+				let elapsed_periods = elapsed.checked_div(period).unwrap_or_else(Zero::zero);
+				let elapsed_periods =
+					BlockNumberToBalance::convert(elapsed_periods).min((*period_count).into());
+				elapsed_periods.saturating_mul(*per_period)
+			},
+			VestingCurve::Cliff { cliff, duration, total } => {
+				if elapsed < *cliff {
+					return Zero::zero();
+				}
+				Self::linear_release::<BlockNumberToBalance>(*total, elapsed, *duration)
+			},
+			VestingCurve::Linear { duration, total } =>
+				Self::linear_release::<BlockNumberToBalance>(*total, elapsed, *duration),
+			VestingCurve::Tranches(tranches) => tranches
+				.iter()
+				.take_while(|(offset, _)| *offset <= elapsed)
+				.fold(Zero::zero(), |vested: Balance, (_, amount)| vested.saturating_add(*amount)),
+			VestingCurve::Milestones { milestone_count, total, released } =>
+				Self::fraction_of(*total, released.len() as u128, (*milestone_count).into()),
+		}
 	}
 
 	/// Returns the remaining locked amount at the given block
 	pub fn locked_amount<BlockNumberToBalance: Convert<BlockNumber, Balance>>(&self, now: BlockNumber) -> Balance {
-		// TODO: Calculate locked amount
-		// Hint: locked_amount = total_amount - vested_amount
-		todo!()
+		self.total_amount()
+			.unwrap_or_else(Bounded::max_value)
+			.saturating_sub(self.vested_amount::<BlockNumberToBalance>(now))
 	}
 
+	/// Returns true if the schedule starts after `current_block` or waits for the TGE
 	pub fn is_valid_start_block(&self, current_block: BlockNumber) -> bool {
-		// TODO: Validate that the start block is in the future
-		// Return true if start > current_block
-		todo!()
+		self.tge_relative || self.start > current_block
+	}
+
+	/// Returns the schedule with `start` counted from `tge` if it is relative to the TGE and the
+	/// TGE is known, otherwise the schedule as is
+	pub fn anchored(&self, tge: Option<BlockNumber>) -> Self {
+		let mut schedule = self.clone();
+		if let (true, Some(tge)) = (self.tge_relative, tge) {
+			schedule.start = tge.saturating_add(self.start);
+			schedule.tge_relative = false;
+		}
+		schedule
+	}
+
+	/// Releases the rest of the schedule `factor` times faster from `now`, keeping the total
+	///
+	/// Periods, durations and offsets are divided by `factor`, and `start` is moved so that no
+	/// less has vested at `now`. Milestone schedules are left as they are.
+	pub fn speed_up(&mut self, factor: u32, now: BlockNumber, tge: Option<BlockNumber>) {
+		if factor <= 1 {
+			return;
+		}
+		let factor: BlockNumber = factor.into();
+		let anchored = self.anchored(tge);
+		let elapsed =
+			if anchored.tge_relative { Zero::zero() } else { now.saturating_sub(anchored.start) };
+		// Rounding up keeps the new curve at least as far along as the old one.
+		let mut scaled_elapsed = elapsed / factor;
+		if !(elapsed % factor).is_zero() {
+			scaled_elapsed.saturating_inc();
+		}
+
+		match &mut self.curve {
+			VestingCurve::Stepped { period, .. } => {
+				let scaled_period = (*period / factor).max(One::one());
+				let elapsed_periods = elapsed.checked_div(period).unwrap_or_else(Zero::zero);
+				scaled_elapsed = scaled_elapsed.max(elapsed_periods.saturating_mul(scaled_period));
+				*period = scaled_period;
+			},
+			VestingCurve::Cliff { cliff, duration, .. } => {
+				*cliff /= factor;
+				*duration = (*duration / factor).max(One::one());
+			},
+			VestingCurve::Linear { duration, .. } => {
+				*duration = (*duration / factor).max(One::one());
+			},
+			VestingCurve::Tranches(tranches) => {
+				// Tranches whose offsets round to the same block are released together.
+				let mut scaled: Vec<(BlockNumber, Balance)> = Vec::new();
+				for (offset, amount) in tranches.iter() {
+					let offset = *offset / factor;
+					match scaled.last_mut() {
+						Some((last, total)) if *last == offset =>
+							*total = total.saturating_add(*amount),
+						_ => scaled.push((offset, *amount)),
+					}
+				}
+				*tranches = BoundedVec::truncate_from(scaled);
+			},
+			VestingCurve::Milestones { .. } => return,
+		}
+		self.start = self.start.saturating_add(elapsed.saturating_sub(scaled_elapsed));
+	}
+
+	/// Releases `part` of what has not been released early yet at once, keeping the total
+	///
+	/// As the curve releases the rest in proportion, `part` of what is still locked unlocks.
+	pub fn vest_now(&mut self, part: Percent) {
+		let total = self.total_amount().unwrap_or_else(Bounded::max_value);
+		let remaining = total.saturating_sub(self.released_early);
+		let released = Self::fraction_of(remaining, part.deconstruct().into(), 100);
+		self.released_early = self.released_early.saturating_add(released);
 	}
 
+	/// Returns the block from which everything is vested, `None` on overflow, while milestones
+	/// are still to be released or while the schedule waits for the TGE
+	pub fn end(&self) -> Option<BlockNumber> {
+		if self.tge_relative {
+			return None;
+		}
+		if self.total_amount().is_some_and(|total| self.released_early >= total) {
+			return Some(self.start);
+		}
+		let duration = match &self.curve {
+			VestingCurve::Stepped { period, period_count, .. } =>
+				period.checked_mul(&(*period_count).into())?,
+			VestingCurve::Cliff { duration, .. } | VestingCurve::Linear { duration, .. } => *duration,
+			VestingCurve::Tranches(tranches) =>
+				tranches.last().map(|(offset, _)| *offset).unwrap_or_else(Zero::zero),
+			VestingCurve::Milestones { milestone_count, released, .. } => {
+				if (released.len() as u32) < *milestone_count {
+					return None;
+				}
+				Zero::zero()
+			},
+		};
+		self.start.checked_add(&duration)
+	}
+
+	/// Returns the first block after `now` at which more of the schedule vests, `None` once
+	/// everything has vested, when only a milestone release can vest more or while the schedule
+	/// waits for the TGE
+	///
+	/// Linear release is reported as the next block, even if rounding holds the vested amount
+	/// back for a few blocks.
+	pub fn next_unlock(&self, now: BlockNumber) -> Option<BlockNumber> {
+		if self.tge_relative ||
+			self.total_amount().is_some_and(|total| self.released_early >= total)
+		{
+			return None;
+		}
+		let elapsed = now.checked_sub(&self.start);
+		let offset = match &self.curve {
+			VestingCurve::Stepped { period, period_count, .. } => {
+				let next_period = elapsed
+					.and_then(|elapsed| elapsed.checked_div(period))
+					.unwrap_or_else(Zero::zero)
+					.saturating_add(One::one());
+				if next_period > (*period_count).into() {
+					return None;
+				}
+				period.checked_mul(&next_period)?
+			},
+			VestingCurve::Cliff { cliff, duration, .. } =>
+				Self::next_linear_unlock(elapsed, *cliff, *duration)?,
+			VestingCurve::Linear { duration, .. } =>
+				Self::next_linear_unlock(elapsed, Zero::zero(), *duration)?,
+			VestingCurve::Tranches(tranches) => tranches
+				.iter()
+				.map(|(offset, _)| *offset)
+				.find(|offset| elapsed.is_none_or(|elapsed| *offset > elapsed))?,
+			VestingCurve::Milestones { milestone_count, released, .. } => {
+				// Released milestones still wait for `start`.
+				if elapsed.is_some() || released.is_empty() || *milestone_count == 0 {
+					return None;
+				}
+				Zero::zero()
+			},
+		};
+		self.start.checked_add(&offset)
+	}
+
+	/// Returns the first block after `now` at which a step of the schedule is released or a linear
+	/// release ends, `None` whenever [`Self::next_unlock`] is
+	pub fn next_release_point(&self, now: BlockNumber) -> Option<BlockNumber> {
+		let next = self.next_unlock(now)?;
+		match &self.curve {
+			VestingCurve::Cliff { cliff, .. } if next > self.start.saturating_add(*cliff) =>
+				self.end(),
+			VestingCurve::Linear { .. } => self.end(),
+			_ => Some(next),
+		}
+	}
+
+	/// Moves `amount` of this schedule's total into a new schedule with the same start, shape and
+	/// grant
+	///
+	/// Returns `None` if `amount` is zero, not below the total, or, for a stepped curve, not a
+	/// multiple of `period_count`. Tranches are taken from the latest ones first, and what was
+	/// released early is shared in proportion. Half of the storage deposit moves to the new
+	/// schedule, so that each entry stays backed by part of it.
+	pub fn split_off(&mut self, amount: Balance) -> Option<Self> {
+		let total = self.total_amount()?;
+		if amount.is_zero() || amount >= total {
+			return None;
+		}
+		let curve = match &mut self.curve {
+			VestingCurve::Stepped { period, period_count, per_period } => {
+				let count: Balance = (*period_count).into();
+				if count.is_zero() || !(amount % count).is_zero() {
+					return None;
+				}
+				let split = amount / count;
+				*per_period = per_period.saturating_sub(split);
+				VestingCurve::Stepped { period: *period, period_count: *period_count, per_period: split }
+			},
+			VestingCurve::Cliff { cliff, duration, total } => {
+				*total = total.saturating_sub(amount);
+				VestingCurve::Cliff { cliff: *cliff, duration: *duration, total: amount }
+			},
+			VestingCurve::Linear { duration, total } => {
+				*total = total.saturating_sub(amount);
+				VestingCurve::Linear { duration: *duration, total: amount }
+			},
+			VestingCurve::Tranches(tranches) => {
+				let mut remaining = amount;
+				let mut split = Vec::new();
+				for (offset, tranche) in tranches.iter_mut().rev() {
+					if remaining.is_zero() {
+						break;
+					}
+					let taken = remaining.min(*tranche);
+					*tranche = tranche.saturating_sub(taken);
+					remaining = remaining.saturating_sub(taken);
+					split.push((*offset, taken));
+				}
+				tranches.retain(|(_, tranche)| !tranche.is_zero());
+				split.reverse();
+				VestingCurve::Tranches(BoundedVec::truncate_from(split))
+			},
+			VestingCurve::Milestones { milestone_count, total, released } => {
+				*total = total.saturating_sub(amount);
+				VestingCurve::Milestones {
+					milestone_count: *milestone_count,
+					total: amount,
+					released: released.clone(),
+				}
+			},
+		};
+		let released_early =
+			Self::fraction_of(self.released_early, amount.saturated_into(), total.saturated_into());
+		self.released_early = self.released_early.saturating_sub(released_early);
+		let deposit = self.deposit / 2u32.into();
+		self.deposit = self.deposit.saturating_sub(deposit);
+		Some(Self {
+			start: self.start,
+			curve,
+			grantor: self.grantor.clone(),
+			revocable: self.revocable,
+			tge_relative: self.tge_relative,
+			released_early,
+			deposit,
+		})
+	}
+
+	/// The offset from `start` of the next block releasing more of a linear curve that starts
+	/// after `cliff`
+	fn next_linear_unlock(
+		elapsed: Option<BlockNumber>,
+		cliff: BlockNumber,
+		duration: BlockNumber,
+	) -> Option<BlockNumber> {
+		match elapsed {
+			Some(elapsed) if elapsed >= duration => None,
+			Some(elapsed) if elapsed >= cliff => elapsed.checked_add(&One::one()),
+			_ => Some(cliff.max(One::one())),
+		}
+	}
+
+	/// `total × elapsed / duration`, computed without overflowing and capped at `total`
+	fn linear_release<BlockNumberToBalance: Convert<BlockNumber, Balance>>(
+		total: Balance,
+		elapsed: BlockNumber,
+		duration: BlockNumber,
+	) -> Balance {
+		if elapsed >= duration {
+			return total;
+		}
+		Self::fraction_of(
+			total,
+			BlockNumberToBalance::convert(elapsed).saturated_into(),
+			BlockNumberToBalance::convert(duration).saturated_into(),
+		)
+	}
+
+	/// `total × part / whole` rounded down, computed without overflowing and capped at `total`
+	fn fraction_of(total: Balance, part: u128, whole: u128) -> Balance {
+		if part >= whole {
+			return total;
+		}
+		multiply_by_rational_with_rounding(total.saturated_into(), part, whole, Rounding::Down)
+			.map(|vested: u128| vested.saturated_into())
+			.unwrap_or(total)
+	}
+}
+
+/// How `accelerate_vesting` speeds up an account's schedules
+#[derive(
+	Clone, Copy, Encode, Decode, MaxEncodedLen, TypeInfo, Debug, PartialEq, Eq,
+	DecodeWithMemTracking,
+)]
+pub enum Acceleration {
+	/// Release the rest of every schedule this many times faster
+	SpeedUp(u32),
+	/// Release this part of what every schedule still locks at once
+	VestNow(Percent),
+}
+
+/// An acceleration of an account's schedules, kept for audits
+#[derive(Clone, Encode, Decode, MaxEncodedLen, TypeInfo, Debug, PartialEq, Eq)]
+pub struct AccelerationRecord<BlockNumber, Balance> {
+	/// The block the acceleration was applied at
+	pub at: BlockNumber,
+	/// How the schedules were accelerated
+	pub acceleration: Acceleration,
+	/// The amount that unlocked at once
+	pub unlocked: Balance,
+}
+
+/// The maximum number of entries in an account's unlock calendar.
+pub type MaxCalendarEntries = ConstU32<256>;
+
+/// A schedule end as stored in [`ScheduleEnds`], big-endian so that keys iterate in time order.
+pub type ScheduleEndKey = [u8; 8];
+
+/// The [`ScheduleEndKey`] of `end`.
+pub fn schedule_end_key<BlockNumber: UniqueSaturatedInto<u64>>(end: BlockNumber) -> ScheduleEndKey {
+	end.saturated_into::<u64>().to_be_bytes()
+}
+
+/// Why a grant in `batch_vested_transfer` was rejected
+#[derive(Clone, Encode, Decode, DecodeWithMemTracking, TypeInfo, PalletError, Debug, PartialEq, Eq)]
+pub enum BatchGrantError {
+	/// The recipient already has `MaxVestingSchedules` schedules
+	TooManyVestingSchedules,
+	/// The schedule vests less than `MinVestedTransfer`
+	AmountLow,
+	/// The schedule does not start in the future
+	InvalidVestingStart,
+	/// The schedule's curve is malformed
+	InvalidSchedule,
+	/// The recipient does not accept grants from the sender
+	GrantorNotAccepted,
+	/// The transfer to the recipient failed
+	TransferFailed,
 }
 
 #[frame::pallet]
@@ -99,9 +577,41 @@ pub mod pallet {
 
 	#[pallet::config]
 	pub trait Config: polkadot_sdk::frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as polkadot_sdk::frame_system::Config>::RuntimeEvent>;
 
-		type Currency: LockableCurrency<Self::AccountId, Moment = BlockNumberFor<Self>>;
+		/// The overarching freeze reason.
+		type RuntimeFreezeReason: From<FreezeReason>;
 
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
+
+		type Currency: Mutate<Self::AccountId>
+			+ MutateFreeze<Self::AccountId, Id = Self::RuntimeFreezeReason>
+			+ MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+
+		/// The identifier of the assets schedules can vest besides `Currency`
+		type AssetId: Parameter + MaxEncodedLen + Copy;
+
+		/// The assets schedules can vest besides `Currency`
+		type Assets: fungibles::Mutate<
+			Self::AccountId,
+			AssetId = Self::AssetId,
+			Balance = BalanceOf<Self>,
+		>;
+
+		/// Freezes vesting `Assets` with [`FreezeReason::Vesting`], usually
+		/// `pallet_assets_freezer`
+		type AssetsFreezer: fungibles::MutateFreeze<
+			Self::AccountId,
+			AssetId = Self::AssetId,
+			Balance = BalanceOf<Self>,
+			Id = Self::RuntimeFreezeReason,
+		>;
+
+		/// The clock schedules are measured against, usually `frame_system::Pallet<Self>`
+		type BlockNumberProvider: BlockNumberProvider<BlockNumber: MaybeSerializeDeserialize>;
 
 		/// Convert the block number into a balance.
 		type BlockNumberToBalance: Convert<BlockNumberFor<Self>, BalanceOf<Self>>;
@@ -110,14 +620,66 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxVestingSchedules: Get<u32>;
 
+		/// The maximum number of `Assets` an account can have vesting schedules in
+		#[pallet::constant]
+		type MaxVestingAssets: Get<u32>;
+
 		/// The minimum vesting amount
 		#[pallet::constant]
 		type MinVestedTransfer: Get<BalanceOf<Self>>;
+
+		/// The storage deposit a grantor puts on hold for each schedule it creates
+		#[pallet::constant]
+		type ScheduleDeposit: Get<BalanceOf<Self>>;
+
+		/// Whether `unfreeze_vesting` pushes schedules back by the time they spent frozen, instead
+		/// of releasing what vested meanwhile at once
+		#[pallet::constant]
+		type ExtendFrozenVesting: Get<bool>;
+
+		/// The maximum number of grantors an account can accept schedules from
+		#[pallet::constant]
+		type MaxAcceptedGrantors: Get<u32>;
+
+		/// The maximum number of grants in one `batch_vested_transfer`
+		#[pallet::constant]
+		type MaxBatchSize: Get<u32>;
+
+		/// The origin allowed to overwrite and remove any account's schedules, usually Root or a
+		/// governance body
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The origin allowed to release the milestones of [`VestingCurve::Milestones`] schedules
+		type MilestoneOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Provides an asset for the benchmarks to vest.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AssetId>;
 	}
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	/// A reason for the pallet freezing funds.
+	#[pallet::composite_enum]
+	pub enum FreezeReason {
+		/// Funds are frozen until their vesting schedule releases them.
+		#[codec(index = 0)]
+		Vesting,
+	}
+
+	/// A reason for the pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// The storage deposit of the vesting schedules an account granted.
+		#[codec(index = 0)]
+		ScheduleDeposit,
+	}
+
 	/// Vesting schedules for an account
 	#[pallet::storage]
 	#[pallet::getter(fn vesting_schedules)]
@@ -129,7 +691,245 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
-	
+	/// The account receiving the funds a grantor revokes, the grantor itself if unset
+	#[pallet::storage]
+	#[pallet::getter(fn revoke_beneficiary)]
+	pub type RevokeBeneficiary<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+	/// Vesting schedules of an account in an asset of `Config::Assets`
+	#[pallet::storage]
+	#[pallet::getter(fn asset_vesting_schedules)]
+	pub type AssetVestingSchedules<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<VestingScheduleOf<T>, T::MaxVestingSchedules>,
+		ValueQuery,
+	>;
+
+	/// The assets an account has vesting schedules in
+	#[pallet::storage]
+	#[pallet::getter(fn vesting_assets)]
+	pub type VestingAssets<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<T::AssetId, T::MaxVestingAssets>,
+		ValueQuery,
+	>;
+
+	/// The minimum vesting amount of an asset, `MinVestedTransfer` if unset
+	#[pallet::storage]
+	pub type AssetMinVestedTransfer<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, BalanceOf<T>, OptionQuery>;
+
+	/// Accounts whose vesting is frozen by `AdminOrigin`, with the block the freeze started
+	#[pallet::storage]
+	#[pallet::getter(fn frozen_since)]
+	pub type FrozenSince<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+	/// The only grantors an account accepts new schedules from, any grantor if unset
+	#[pallet::storage]
+	#[pallet::getter(fn accepted_grantors)]
+	pub type AcceptedGrantors<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<T::AccountId, T::MaxAcceptedGrantors>,
+		OptionQuery,
+	>;
+
+	/// Every acceleration `AdminOrigin` applied to an account's schedules, by the order it was
+	/// applied in
+	#[pallet::storage]
+	#[pallet::getter(fn vesting_accelerations)]
+	pub type VestingAccelerations<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		u32,
+		AccelerationRecord<BlockNumberFor<T>, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// The number of accelerations recorded in [`VestingAccelerations`] for an account
+	#[pallet::storage]
+	#[pallet::getter(fn acceleration_count)]
+	pub type AccelerationCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// The token generation event block that schedules with `tge_relative` count their start from
+	#[pallet::storage]
+	#[pallet::getter(fn tge_block)]
+	pub type TgeBlock<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// Accounts with a schedule that fully vests at the given block, for `on_idle` to clean up
+	///
+	/// The `Identity` hasher keeps the entries ordered by end, so the next one due is the first.
+	#[pallet::storage]
+	pub type ScheduleEnds<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		ScheduleEndKey,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	/// Like [`ScheduleEnds`], for TGE-relative schedules that fully vest the given number of
+	/// blocks after the TGE
+	#[pallet::storage]
+	pub type TgeScheduleEnds<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		ScheduleEndKey,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::genesis_config]
+	#[derive(DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// The vesting schedules to create, as `(who, schedule)`. The vested funds must already be
+		/// in `who`'s balance.
+		pub vesting: Vec<(T::AccountId, VestingScheduleOf<T>)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			let now = T::BlockNumberProvider::current_block_number();
+			for (who, schedule) in self.vesting.iter() {
+				// No storage deposit is held for genesis schedules.
+				let schedule = VestingSchedule { deposit: Zero::zero(), ..schedule.clone() };
+				Pallet::<T>::validate_new_schedule(&schedule, now, T::MinVestedTransfer::get())
+					.expect("Genesis vesting schedule must be valid");
+				VestingSchedules::<T>::try_mutate(who, |schedules| {
+					schedules.try_push(schedule.clone())
+				})
+				.expect("Too many genesis vesting schedules for an account");
+				Pallet::<T>::index_schedule_end(who, &schedule);
+			}
+
+			for (who, schedules) in VestingSchedules::<T>::iter() {
+				let locked = Pallet::<T>::calculate_total_locked_amount(&schedules, now)
+					.expect("Genesis vesting locks must not overflow");
+				assert!(
+					T::Currency::balance(&who) >= locked,
+					"Genesis vesting account does not have enough balance to lock"
+				);
+				Pallet::<T>::update_lock(&who).expect("Genesis vesting locks must be applied");
+			}
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A vesting schedule was added to an account
+		VestingScheduleAdded {
+			from: T::AccountId,
+			to: T::AccountId,
+			schedule: VestingScheduleOf<T>,
+		},
+		/// Vested tokens were unlocked for an account
+		Claimed { who: T::AccountId, unlocked: BalanceOf<T>, locked: BalanceOf<T> },
+		/// All vesting schedules of an account were replaced
+		VestingSchedulesUpdated {
+			who: T::AccountId,
+			old_schedules: BoundedVec<VestingScheduleOf<T>, T::MaxVestingSchedules>,
+			new_schedules: BoundedVec<VestingScheduleOf<T>, T::MaxVestingSchedules>,
+		},
+		/// A single vesting schedule of an account was replaced
+		VestingScheduleUpdated {
+			who: T::AccountId,
+			index: u32,
+			old_schedule: VestingScheduleOf<T>,
+			new_schedule: VestingScheduleOf<T>,
+		},
+		/// A vesting schedule was removed from an account
+		VestingScheduleRemoved { who: T::AccountId, index: u32, schedule: VestingScheduleOf<T> },
+		/// An account has no more locked vesting balance
+		VestingCompleted { who: T::AccountId },
+		/// Two vesting schedules were merged, `merged` is `None` if both had fully vested
+		VestingSchedulesMerged {
+			who: T::AccountId,
+			schedule_a: VestingScheduleOf<T>,
+			schedule_b: VestingScheduleOf<T>,
+			merged: Option<VestingScheduleOf<T>>,
+		},
+		/// A grantor revoked a schedule and took back `amount` that had not vested
+		VestingRevoked {
+			who: T::AccountId,
+			index: u32,
+			grantor: T::AccountId,
+			beneficiary: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// A grantor set or cleared the account receiving its revoked funds
+		RevokeBeneficiarySet { grantor: T::AccountId, beneficiary: Option<T::AccountId> },
+		/// Part of a vesting schedule was split off into a new schedule
+		VestingScheduleSplit {
+			who: T::AccountId,
+			index: u32,
+			remaining: VestingScheduleOf<T>,
+			split: VestingScheduleOf<T>,
+		},
+		/// A vesting schedule and the `amount` it still locked moved to another account
+		VestingScheduleTransferred {
+			from: T::AccountId,
+			to: T::AccountId,
+			index: u32,
+			schedule: VestingScheduleOf<T>,
+			amount: BalanceOf<T>,
+		},
+		/// An account set or cleared the grantors it accepts schedules from
+		AcceptedGrantorsSet {
+			who: T::AccountId,
+			grantors: Option<BoundedVec<T::AccountId, T::MaxAcceptedGrantors>>,
+		},
+		/// A vesting schedule in an asset was added
+		AssetVestingScheduleAdded {
+			asset: T::AssetId,
+			from: T::AccountId,
+			to: T::AccountId,
+			schedule: VestingScheduleOf<T>,
+		},
+		/// Vested funds of an asset were claimed
+		AssetClaimed {
+			asset: T::AssetId,
+			who: T::AccountId,
+			unlocked: BalanceOf<T>,
+			locked: BalanceOf<T>,
+		},
+		/// The minimum vesting amount of an asset was set or cleared
+		AssetMinVestedTransferSet { asset: T::AssetId, amount: Option<BalanceOf<T>> },
+		/// An account's vesting was frozen
+		VestingFrozen { who: T::AccountId },
+		/// An account's vesting was unfrozen after `frozen_for`, pushing its schedules back if
+		/// `extended`
+		VestingUnfrozen { who: T::AccountId, frozen_for: BlockNumberFor<T>, extended: bool },
+		/// A milestone was released, leaving `locked` frozen across the account's schedules
+		MilestoneReleased {
+			who: T::AccountId,
+			index: u32,
+			milestone_id: u32,
+			locked: BalanceOf<T>,
+		},
+		/// The token generation event block was set
+		TgeBlockSet { block: BlockNumberFor<T> },
+		/// An account's schedules were accelerated, unlocking `unlocked` at once
+		VestingAccelerated { who: T::AccountId, acceleration: Acceleration, unlocked: BalanceOf<T> },
+	}
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Vesting period is zero
@@ -150,6 +950,57 @@ pub mod pallet {
 		ArithmeticOverflow,
 		/// Invalid vesting start
 		InvalidVestingStart,
+		/// The cliff is longer than the vesting duration
+		InvalidCliff,
+		/// Tranche offsets are not strictly increasing
+		UnsortedTranches,
+		/// A schedule cannot be merged with itself
+		ScheduleIndexesEqual,
+		/// The schedule cannot be split at this amount
+		InvalidSplitAmount,
+		/// The schedules were granted by different accounts or with different revocability, one of
+		/// them is not linear, or one of them is released by milestones or waits for the TGE
+		IncompatibleSchedules,
+		/// Only the grantor can revoke a schedule
+		NotGrantor,
+		/// The schedule was not granted as revocable
+		NotRevocable,
+		/// The schedule is not released by milestones
+		NotMilestoneSchedule,
+		/// The milestone does not exist or milestones are listed out of order
+		InvalidMilestone,
+		/// The milestone has already been released
+		MilestoneAlreadyReleased,
+		/// The recipient only accepts schedules from other grantors
+		GrantorNotAccepted,
+		/// The account's vesting is already frozen
+		AlreadyFrozen,
+		/// The account's vesting is not frozen
+		NotFrozen,
+		/// The account's vesting is frozen, so its schedules cannot be changed
+		AccountFrozen,
+		/// The grant at `index` of a batch was rejected, and none of the batch was applied
+		BatchGrantFailed { index: u16, reason: BatchGrantError },
+		/// The token generation event block has already been set
+		TgeAlreadySet,
+		/// The speed-up factor is not above one or the part to vest is zero
+		InvalidAcceleration,
+		/// The account already has vesting schedules in `MaxVestingAssets` assets
+		TooManyVestingAssets,
+		/// Asset schedules cannot be granted as revocable
+		RevocableAssetSchedule,
+		/// The token generation event block has already passed
+		TgeBlockInPast,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<frame_system::pallet_prelude::BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(
+			_n: frame_system::pallet_prelude::BlockNumberFor<T>,
+			remaining_weight: Weight,
+		) -> Weight {
+			Self::cleanup_completed(remaining_weight)
+		}
 	}
 
 	#[pallet::call]
@@ -158,116 +1009,823 @@ pub mod pallet {
 		///
 		/// The dispatch origin for this call must be _Signed_.
 		///
+		/// The sender also puts `ScheduleDeposit` on hold until the schedule is removed.
+		///
 		/// - `dest`: The account that will receive the vested transfer
-		/// - `schedule`: The vesting schedule
+		/// - `schedule`: The vesting schedule, its `grantor` and `deposit` are set by the pallet
 		///
 		#[pallet::call_index(0)]
-		#[pallet::weight({10_000})]
+		#[pallet::weight(T::WeightInfo::vested_transfer(T::MaxVestingSchedules::get()))]
 		pub fn vested_transfer(
 			origin: OriginFor<T>,
 			dest: T::AccountId,
 			schedule: VestingScheduleOf<T>,
 		) -> DispatchResult {
-			// TODO: Implement vested_transfer extrinsic
-			// 1. Ensure the origin is signed
-			// 2. Call do_vested_transfer helper function
-			// Hint: Use ensure_signed(origin)? to get the sender
-			todo!()
+			let from = ensure_signed(origin)?;
+			Self::do_vested_transfer(&from, &dest, schedule)
 		}
 
 		/// Claim vested tokens
 		///
 		/// The dispatch origin for this call must be _Signed_.
 		///
+		/// Schedules that have fully vested are removed and their storage deposits returned.
+		///
 		#[pallet::call_index(1)]
-		#[pallet::weight({10_000})]
+		#[pallet::weight(T::WeightInfo::claim(T::MaxVestingSchedules::get()))]
 		pub fn claim(origin: OriginFor<T>) -> DispatchResult {
-			// TODO: Implement claim extrinsic
-			// 1. Ensure the origin is signed
-			// 2. Update the lock for the account
-			// 3. If locked amount is zero, remove the lock entirely
-			// Hint: Use Self::update_lock() and T::Currency::remove_lock()
-			todo!()
+			let who = ensure_signed(origin)?;
+
+			let now = Self::vesting_now(&who);
+			let tge = TgeBlock::<T>::get();
+			let mut completed = Vec::new();
+			VestingSchedules::<T>::mutate_exists(&who, |maybe_schedules| {
+				Self::take_completed(maybe_schedules, tge, now, &mut completed)
+			});
+			for schedule in completed.iter() {
+				Self::release_deposit(schedule);
+			}
+
+			let previously_locked =
+				T::Currency::balance_frozen(&FreezeReason::Vesting.into(), &who);
+			let locked = Self::update_lock(&who)?;
+
+			Self::deposit_event(Event::Claimed {
+				who: who.clone(),
+				unlocked: previously_locked.saturating_sub(locked),
+				locked,
+			});
+			if locked.is_zero() && !previously_locked.is_zero() {
+				Self::deposit_event(Event::VestingCompleted { who });
+			}
+			Ok(())
 		}
 
 
 		/// Update vesting schedules
 		///
-		/// The dispatch origin for this call must be _Root_.
+		/// The dispatch origin for this call must be `AdminOrigin`.
+		///
+		/// The storage deposits of the replaced schedules are returned to their grantors.
 		///
-		/// - `schedules`: The new vesting schedules
+		/// - `schedules`: The new vesting schedules, their `deposit` is cleared as none is held
 		///
 		#[pallet::call_index(2)]
-		#[pallet::weight({10_000})]
+		#[pallet::weight(T::WeightInfo::update_vesting_schedules(T::MaxVestingSchedules::get()))]
 		pub fn update_vesting_schedules(
 			origin: OriginFor<T>,
 			who: T::AccountId,
 			schedules: Vec<VestingScheduleOf<T>>,
 		) -> DispatchResult {
-			// TODO: Implement update_vesting_schedules extrinsic
-			// 1. Ensure the origin is root
-			// 2. Convert schedules Vec to BoundedVec
-			// 3. Calculate total locked amount for validation
-			// 4. Ensure account has enough balance
-			// 5. Update storage and locks
-			// Hint: Use ensure_root(origin)? and try_into() for BoundedVec conversion
-			todo!()
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let schedules: Vec<_> = schedules
+				.into_iter()
+				.map(|schedule| VestingSchedule { deposit: Zero::zero(), ..schedule })
+				.collect();
+			let new_schedules: BoundedVec<VestingScheduleOf<T>, T::MaxVestingSchedules> =
+				schedules.try_into().map_err(|_| Error::<T>::TooManyVestingSchedules)?;
+			for schedule in new_schedules.iter() {
+				Self::ensure_valid_schedule(schedule)?;
+			}
+
+			let total_locked =
+				Self::calculate_total_locked_amount(&new_schedules, Self::vesting_now(&who))?;
+			ensure!(
+				T::Currency::total_balance(&who) >= total_locked,
+				Error::<T>::InsufficientBalanceToLock
+			);
+
+			let old_schedules = VestingSchedules::<T>::get(&who);
+			for schedule in old_schedules.iter() {
+				Self::release_deposit(schedule);
+			}
+			if new_schedules.is_empty() {
+				VestingSchedules::<T>::remove(&who);
+			} else {
+				VestingSchedules::<T>::insert(&who, new_schedules.clone());
+			}
+			Self::update_lock(&who)?;
+			for schedule in new_schedules.iter() {
+				Self::index_schedule_end(&who, schedule);
+			}
+
+			Self::deposit_event(Event::VestingSchedulesUpdated { who, old_schedules, new_schedules });
+			Ok(())
 		}
 
 
 		/// Update a specific vesting schedule
 		///
-		/// The dispatch origin for this call must be _Root_.
+		/// The dispatch origin for this call must be `AdminOrigin`.
+		///
+		/// The storage deposit of the replaced schedule is returned to its grantor.
 		///
 		/// - `who`: The account whose vesting schedule will be updated
 		/// - `index`: The index of the vesting schedule to update
-		/// - `schedule`: The new vesting schedule
+		/// - `schedule`: The new vesting schedule, its `deposit` is cleared as none is held
 		///
 		#[pallet::call_index(3)]
-		#[pallet::weight({10_000})]
+		#[pallet::weight(T::WeightInfo::update_vesting_schedule(T::MaxVestingSchedules::get()))]
 		pub fn update_vesting_schedule(
 			origin: OriginFor<T>,
 			who: T::AccountId,
 			index: u32,
-			schedule: VestingScheduleOf<T>,
+			mut schedule: VestingScheduleOf<T>,
 		) -> DispatchResult {
-			// TODO: Implement update_vesting_schedule extrinsic
-			// 1. Ensure the origin is root
-			// 2. Validate the new schedule (period > 0, period_count > 0, per_period > 0)
-			// 3. Use VestingSchedules::<T>::try_mutate to update the specific schedule
-			// 4. Update the lock after modifying the schedule
-			// Hints:
-			// - Use get_mut() to get mutable reference to the schedule at index
-			// - Return InvalidVestingIndex error if index is out of bounds
-			todo!()
+			T::AdminOrigin::ensure_origin(origin)?;
+			schedule.deposit = Zero::zero();
+			let total = Self::ensure_valid_schedule(&schedule)?;
+			ensure!(!total.is_zero(), Error::<T>::AmountLow);
+
+			let old_schedule = VestingSchedules::<T>::try_mutate(&who, |schedules| {
+				let existing =
+					schedules.get_mut(index as usize).ok_or(Error::<T>::InvalidVestingIndex)?;
+				Ok::<_, DispatchError>(core::mem::replace(existing, schedule.clone()))
+			})?;
+			Self::release_deposit(&old_schedule);
+			Self::update_lock(&who)?;
+			Self::index_schedule_end(&who, &schedule);
+
+			Self::deposit_event(Event::VestingScheduleUpdated {
+				who,
+				index,
+				old_schedule,
+				new_schedule: schedule,
+			});
+			Ok(())
 		}
 
 		/// Force remove a vesting schedule
 		///
-		/// The dispatch origin for this call must be _Root_.
+		/// The dispatch origin for this call must be `AdminOrigin`.
+		///
+		/// The storage deposit of the schedule is returned to its grantor.
 		///
 		/// - `who`: The account whose vesting schedule will be removed
 		/// - `schedule_index`: The index of the vesting schedule to remove
 		#[pallet::call_index(4)]
-		#[pallet::weight({10_000})]
+		#[pallet::weight(T::WeightInfo::force_remove_vesting_schedule(T::MaxVestingSchedules::get()))]
 		pub fn force_remove_vesting_schedule(
 			origin: OriginFor<T>,
 			who: T::AccountId,
 			schedule_index: u32,
 		) -> DispatchResult {
-			// TODO: Implement force_remove_vesting_schedule extrinsic
-			// 1. Ensure the origin is root
-			// 2. Use VestingSchedules::<T>::try_mutate to modify the schedules
-			// 3. Validate the schedule_index is within bounds
-			// 4. Remove the schedule at the given index
-			// 5. If no schedules remain, remove the lock entirely
-			// 6. Otherwise, update the lock with remaining schedules
-			// Hints:
-			// - Use schedules.remove(index) to remove at specific index
-			// - Use T::Currency::remove_lock() when schedules is empty
-			todo!()
-		}
-		
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let schedule = VestingSchedules::<T>::try_mutate_exists(&who, |maybe_schedules| {
+				let schedules = maybe_schedules.as_mut().ok_or(Error::<T>::NotVested)?;
+				let index = schedule_index as usize;
+				ensure!(index < schedules.len(), Error::<T>::InvalidVestingIndex);
+				let schedule = schedules.remove(index);
+				if schedules.is_empty() {
+					*maybe_schedules = None;
+				}
+				Ok::<_, DispatchError>(schedule)
+			})?;
+			Self::release_deposit(&schedule);
+			Self::update_lock(&who)?;
+
+			Self::deposit_event(Event::VestingScheduleRemoved {
+				who,
+				index: schedule_index,
+				schedule,
+			});
+			Ok(())
+		}
+
+		/// Merge two vesting schedules into one
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// The merged schedule locks what both schedules still lock and releases it linearly from
+		/// the current block, or the later start if a schedule has not begun, until the later end.
+		/// It is appended after the remaining schedules. Only linear schedules can be merged, as a
+		/// linear release would unlock other curves early, unless both are fully vested.
+		///
+		/// - `index_a`: The index of the first schedule to merge
+		/// - `index_b`: The index of the second schedule to merge
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::merge_schedules(T::MaxVestingSchedules::get()))]
+		pub fn merge_schedules(origin: OriginFor<T>, index_a: u32, index_b: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(index_a != index_b, Error::<T>::ScheduleIndexesEqual);
+			ensure!(!FrozenSince::<T>::contains_key(&who), Error::<T>::AccountFrozen);
+
+			let now = T::BlockNumberProvider::current_block_number();
+			let tge = TgeBlock::<T>::get();
+			let (schedule_a, schedule_b, merged) =
+				VestingSchedules::<T>::try_mutate_exists(&who, |maybe_schedules| {
+					let schedules = maybe_schedules.as_mut().ok_or(Error::<T>::NotVested)?;
+					let (index_a, index_b) = (index_a as usize, index_b as usize);
+					ensure!(
+						index_a < schedules.len() && index_b < schedules.len(),
+						Error::<T>::InvalidVestingIndex
+					);
+
+					// Remove the higher index first so the lower one stays valid.
+					let (schedule_a, schedule_b) = if index_a > index_b {
+						let schedule_a = schedules.remove(index_a);
+						(schedule_a, schedules.remove(index_b))
+					} else {
+						let schedule_b = schedules.remove(index_b);
+						(schedules.remove(index_a), schedule_b)
+					};
+
+					let merged = Self::merge_pair(
+						&schedule_a.anchored(tge),
+						&schedule_b.anchored(tge),
+						now,
+					)?;
+					if let Some(merged) = &merged {
+						schedules
+							.try_push(merged.clone())
+							.map_err(|_| Error::<T>::TooManyVestingSchedules)?;
+					}
+					if schedules.is_empty() {
+						*maybe_schedules = None;
+					}
+					Ok::<_, DispatchError>((schedule_a, schedule_b, merged))
+				})?;
+			// The merged schedule keeps one deposit, the other is returned.
+			Self::release_deposit(&schedule_a);
+			if merged.is_none() {
+				Self::release_deposit(&schedule_b);
+			}
+			Self::update_lock(&who)?;
+			if let Some(merged) = &merged {
+				Self::index_schedule_end(&who, merged);
+			}
+
+			Self::deposit_event(Event::VestingSchedulesMerged { who, schedule_a, schedule_b, merged });
+			Ok(())
+		}
+
+		/// Split part of a vesting schedule off into a new schedule
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// The new schedule keeps the start and release curve of the original and is appended after
+		/// the existing schedules. It takes half of the original's storage deposit.
+		///
+		/// - `index`: The index of the schedule to split
+		/// - `amount`: The part of the schedule's total amount to move into the new schedule
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::split_schedule(T::MaxVestingSchedules::get()))]
+		pub fn split_schedule(origin: OriginFor<T>, index: u32, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!FrozenSince::<T>::contains_key(&who), Error::<T>::AccountFrozen);
+
+			let (remaining, split) = VestingSchedules::<T>::try_mutate(&who, |schedules| {
+				let schedule =
+					schedules.get_mut(index as usize).ok_or(Error::<T>::InvalidVestingIndex)?;
+				let split = schedule.split_off(amount).ok_or(Error::<T>::InvalidSplitAmount)?;
+				let remaining = schedule.clone();
+				schedules
+					.try_push(split.clone())
+					.map_err(|_| Error::<T>::TooManyVestingSchedules)?;
+				Ok::<_, DispatchError>((remaining, split))
+			})?;
+			Self::update_lock(&who)?;
+			Self::index_schedule_end(&who, &split);
+
+			Self::deposit_event(Event::VestingScheduleSplit { who, index, remaining, split });
+			Ok(())
+		}
+
+		/// Revoke a revocable vesting schedule
+		///
+		/// The dispatch origin for this call must be _Signed_ by the schedule's grantor.
+		///
+		/// The schedule is removed. What has vested stays with `who`, the rest is transferred to
+		/// the grantor's revoke beneficiary, or the grantor if none is set. Fails while `who`'s
+		/// vesting is frozen.
+		///
+		/// - `who`: The account holding the vesting schedule
+		/// - `index`: The index of the vesting schedule to revoke
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::revoke(T::MaxVestingSchedules::get()))]
+		pub fn revoke(origin: OriginFor<T>, who: T::AccountId, index: u32) -> DispatchResult {
+			let grantor = ensure_signed(origin)?;
+			ensure!(!FrozenSince::<T>::contains_key(&who), Error::<T>::AccountFrozen);
+
+			let now = T::BlockNumberProvider::current_block_number();
+			let (locked, schedule) =
+				VestingSchedules::<T>::try_mutate_exists(&who, |maybe_schedules| {
+					let schedules = maybe_schedules.as_mut().ok_or(Error::<T>::NotVested)?;
+					let schedule =
+						schedules.get(index as usize).ok_or(Error::<T>::InvalidVestingIndex)?;
+					ensure!(schedule.grantor.as_ref() == Some(&grantor), Error::<T>::NotGrantor);
+					ensure!(schedule.revocable, Error::<T>::NotRevocable);
+
+					let locked = schedule
+						.anchored(TgeBlock::<T>::get())
+						.locked_amount::<T::BlockNumberToBalance>(now);
+					let schedule = schedules.remove(index as usize);
+					if schedules.is_empty() {
+						*maybe_schedules = None;
+					}
+					Ok::<_, DispatchError>((locked, schedule))
+				})?;
+			Self::release_deposit(&schedule);
+			Self::update_lock(&who)?;
+
+			// Funds put on hold elsewhere still count towards the frozen amount, so only take
+			// back what can actually be moved.
+			let amount = locked.min(T::Currency::reducible_balance(
+				&who,
+				Preservation::Expendable,
+				Fortitude::Polite,
+			));
+			let beneficiary =
+				RevokeBeneficiary::<T>::get(&grantor).unwrap_or_else(|| grantor.clone());
+			if !amount.is_zero() {
+				T::Currency::transfer(&who, &beneficiary, amount, Preservation::Expendable)?;
+			}
+
+			Self::deposit_event(Event::VestingRevoked { who, index, grantor, beneficiary, amount });
+			Ok(())
+		}
+
+		/// Set the account that receives the funds the sender revokes
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `beneficiary`: The receiving account, `None` to receive revoked funds directly
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::set_revoke_beneficiary())]
+		pub fn set_revoke_beneficiary(
+			origin: OriginFor<T>,
+			beneficiary: Option<T::AccountId>,
+		) -> DispatchResult {
+			let grantor = ensure_signed(origin)?;
+			RevokeBeneficiary::<T>::set(&grantor, beneficiary.clone());
+
+			Self::deposit_event(Event::RevokeBeneficiarySet { grantor, beneficiary });
+			Ok(())
+		}
+
+		/// Move a vesting schedule and the funds it still locks to another account
+		///
+		/// The dispatch origin for this call must be _Signed_, and accepted as a grantor by
+		/// `new_owner`.
+		///
+		/// What has already vested stays with the sender. Fails while the vesting of either account
+		/// is frozen.
+		///
+		/// - `index`: The index of the schedule to transfer
+		/// - `new_owner`: The account receiving the schedule
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::transfer_schedule(T::MaxVestingSchedules::get()))]
+		pub fn transfer_schedule(
+			origin: OriginFor<T>,
+			index: u32,
+			new_owner: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				!FrozenSince::<T>::contains_key(&who) && !FrozenSince::<T>::contains_key(&new_owner),
+				Error::<T>::AccountFrozen
+			);
+			Self::ensure_accepted_grantor(&who, &new_owner)?;
+
+			let now = T::BlockNumberProvider::current_block_number();
+			let schedule = VestingSchedules::<T>::try_mutate_exists(&who, |maybe_schedules| {
+				let schedules = maybe_schedules.as_mut().ok_or(Error::<T>::NotVested)?;
+				ensure!((index as usize) < schedules.len(), Error::<T>::InvalidVestingIndex);
+				let schedule = schedules.remove(index as usize);
+				if schedules.is_empty() {
+					*maybe_schedules = None;
+				}
+				Ok::<_, DispatchError>(schedule)
+			})?;
+			VestingSchedules::<T>::try_mutate(&new_owner, |schedules| {
+				schedules
+					.try_push(schedule.clone())
+					.map_err(|_| Error::<T>::TooManyVestingSchedules)
+			})?;
+
+			// The sender's freeze has to shrink before the locked funds can move.
+			Self::update_lock(&who)?;
+			let amount = schedule
+				.anchored(TgeBlock::<T>::get())
+				.locked_amount::<T::BlockNumberToBalance>(now);
+			T::Currency::transfer(&who, &new_owner, amount, Preservation::Expendable)?;
+			Self::update_lock(&new_owner)?;
+			Self::unindex_schedule_end(&who, &schedule);
+			Self::index_schedule_end(&new_owner, &schedule);
+
+			Self::deposit_event(Event::VestingScheduleTransferred {
+				from: who,
+				to: new_owner,
+				index,
+				schedule,
+				amount,
+			});
+			Ok(())
+		}
+
+		/// Release a milestone of a milestone schedule, unlocking its share of the schedule.
+		///
+		/// The dispatch origin for this call must be `MilestoneOrigin`.
+		///
+		/// Fails while `who`'s vesting is frozen.
+		///
+		/// - `who`: The account whose schedule reached the milestone
+		/// - `schedule_index`: The index of the milestone schedule
+		/// - `milestone_id`: The milestone to release, below the schedule's `milestone_count`
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::release_milestone(T::MaxVestingSchedules::get()))]
+		pub fn release_milestone(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			schedule_index: u32,
+			milestone_id: u32,
+		) -> DispatchResult {
+			T::MilestoneOrigin::ensure_origin(origin)?;
+			ensure!(!FrozenSince::<T>::contains_key(&who), Error::<T>::AccountFrozen);
+
+			let schedule = VestingSchedules::<T>::try_mutate(&who, |schedules| {
+				let schedule = schedules
+					.get_mut(schedule_index as usize)
+					.ok_or(Error::<T>::InvalidVestingIndex)?;
+				let VestingCurve::Milestones { milestone_count, released, .. } = &mut schedule.curve
+				else {
+					return Err(Error::<T>::NotMilestoneSchedule);
+				};
+				ensure!(milestone_id < *milestone_count, Error::<T>::InvalidMilestone);
+				let position = released
+					.binary_search(&milestone_id)
+					.err()
+					.ok_or(Error::<T>::MilestoneAlreadyReleased)?;
+				released
+					.try_insert(position, milestone_id)
+					.map_err(|_| Error::<T>::InvalidMilestone)?;
+				Ok(schedule.clone())
+			})?;
+
+			let locked = Self::update_lock(&who)?;
+			Self::index_schedule_end(&who, &schedule);
+
+			Self::deposit_event(Event::MilestoneReleased {
+				who,
+				index: schedule_index,
+				milestone_id,
+				locked,
+			});
+			Ok(())
+		}
+
+		/// Create a vested transfer to each recipient of `grants`, all or none of them
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Every schedule is checked and the sender's balance is checked against their sum and
+		/// storage deposits before anything moves. A rejected grant fails the whole batch with
+		/// [`Error::BatchGrantFailed`], naming the grant and the reason.
+		///
+		/// - `grants`: The recipients and their schedules, whose `grantor` and `deposit` are set
+		///   by the pallet
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::batch_vested_transfer(grants.len() as u32))]
+		pub fn batch_vested_transfer(
+			origin: OriginFor<T>,
+			mut grants: BoundedVec<(T::AccountId, VestingScheduleOf<T>), T::MaxBatchSize>,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+
+			let now = T::BlockNumberProvider::current_block_number();
+			let mut total = BalanceOf::<T>::zero();
+			for (index, (_, schedule)) in grants.iter_mut().enumerate() {
+				schedule.grantor = Some(from.clone());
+				let amount =
+					Self::validate_new_schedule(schedule, now, T::MinVestedTransfer::get())
+						.map_err(|e| Self::batch_grant_error(index, e))?;
+				total = total
+					.checked_add(&amount)
+					.and_then(|total| total.checked_add(&T::ScheduleDeposit::get()))
+					.ok_or(Error::<T>::ArithmeticOverflow)?;
+			}
+			ensure!(
+				T::Currency::reducible_balance(&from, Preservation::Expendable, Fortitude::Polite) >=
+					total,
+				Error::<T>::InsufficientBalanceToLock
+			);
+
+			for (index, (dest, schedule)) in grants.into_iter().enumerate() {
+				Self::do_vested_transfer(&from, &dest, schedule)
+					.map_err(|e| Self::batch_grant_error(index, e))?;
+			}
+			Ok(())
+		}
+
+		/// Only accept new schedules from `grantors`
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Protects the sender's `MaxVestingSchedules` slots from being filled with unwanted
+		/// grants. Schedules set by `AdminOrigin` are not affected.
+		///
+		/// - `grantors`: The accepted grantors, `None` to accept schedules from anyone
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::set_accepted_grantors(T::MaxAcceptedGrantors::get()))]
+		pub fn set_accepted_grantors(
+			origin: OriginFor<T>,
+			grantors: Option<BoundedVec<T::AccountId, T::MaxAcceptedGrantors>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			AcceptedGrantors::<T>::set(&who, grantors.clone());
+
+			Self::deposit_event(Event::AcceptedGrantorsSet { who, grantors });
+			Ok(())
+		}
+
+		/// Stop an account's vesting without touching its schedules
+		///
+		/// The dispatch origin for this call must be `AdminOrigin`.
+		///
+		/// While frozen, nothing more vests for `who`, its schedules cannot be merged, split,
+		/// transferred or revoked, and none of their milestones can be released.
+		///
+		/// - `who`: The account whose vesting is frozen
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::freeze_vesting())]
+		pub fn freeze_vesting(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(!FrozenSince::<T>::contains_key(&who), Error::<T>::AlreadyFrozen);
+
+			FrozenSince::<T>::insert(&who, T::BlockNumberProvider::current_block_number());
+			Self::deposit_event(Event::VestingFrozen { who });
+			Ok(())
+		}
+
+		/// Resume an account's vesting
+		///
+		/// The dispatch origin for this call must be `AdminOrigin`.
+		///
+		/// With `ExtendFrozenVesting`, every schedule, in `Currency` and in `Assets`, is pushed back
+		/// by the time it spent frozen. Otherwise what vested while frozen is released at once.
+		///
+		/// - `who`: The account whose vesting is unfrozen
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::unfreeze_vesting(
+			T::MaxVestingSchedules::get(),
+			T::MaxVestingAssets::get(),
+		))]
+		pub fn unfreeze_vesting(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let since = FrozenSince::<T>::take(&who).ok_or(Error::<T>::NotFrozen)?;
+
+			let now = T::BlockNumberProvider::current_block_number();
+			let extended = T::ExtendFrozenVesting::get();
+			let mut schedules = VestingSchedules::<T>::get(&who);
+			if extended && !schedules.is_empty() {
+				Self::extend_frozen(schedules.as_mut(), since, now);
+				VestingSchedules::<T>::insert(&who, schedules.clone());
+			}
+			Self::update_lock(&who)?;
+			for schedule in schedules.iter() {
+				Self::index_schedule_end(&who, schedule);
+			}
+
+			for asset in VestingAssets::<T>::get(&who) {
+				let mut schedules = AssetVestingSchedules::<T>::get(asset, &who);
+				if extended {
+					Self::extend_frozen(schedules.as_mut(), since, now);
+					AssetVestingSchedules::<T>::insert(asset, &who, schedules.clone());
+				}
+				Self::update_asset_lock(asset, &who)?;
+				for schedule in schedules.iter() {
+					Self::index_schedule_end(&who, schedule);
+				}
+			}
+
+			Self::deposit_event(Event::VestingUnfrozen {
+				who,
+				frozen_for: now.saturating_sub(since),
+				extended,
+			});
+			Ok(())
+		}
+
+		/// Create a vested transfer in an asset
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Works like `vested_transfer`, with the asset's own minimum vesting amount. The storage
+		/// deposit is held in `Currency`. Asset schedules cannot be revoked, merged, split or
+		/// transferred, so `schedule` must not be `revocable`.
+		///
+		/// - `asset`: The asset to vest
+		/// - `dest`: The account that will receive the vested transfer
+		/// - `schedule`: The vesting schedule, its `grantor` and `deposit` are set by the pallet
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::vested_transfer_asset(T::MaxVestingSchedules::get()))]
+		pub fn vested_transfer_asset(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			dest: T::AccountId,
+			mut schedule: VestingScheduleOf<T>,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+			Self::ensure_accepted_grantor(&from, &dest)?;
+			ensure!(!schedule.revocable, Error::<T>::RevocableAssetSchedule);
+			schedule.grantor = Some(from.clone());
+
+			let now = T::BlockNumberProvider::current_block_number();
+			let total =
+				Self::validate_new_schedule(&schedule, now, Self::min_vested_transfer(asset))?;
+			ensure!(
+				<T::Assets as fungibles::Inspect<_>>::reducible_balance(
+					asset,
+					&from,
+					Preservation::Expendable,
+					Fortitude::Polite,
+				) >= total,
+				Error::<T>::InsufficientBalanceToLock
+			);
+
+			schedule.deposit = Self::hold_deposit(&from)?;
+			AssetVestingSchedules::<T>::try_mutate(asset, &dest, |schedules| {
+				schedules
+					.try_push(schedule.clone())
+					.map_err(|_| Error::<T>::TooManyVestingSchedules)
+			})?;
+			VestingAssets::<T>::try_mutate(&dest, |assets| {
+				if !assets.contains(&asset) {
+					assets.try_push(asset).map_err(|_| Error::<T>::TooManyVestingAssets)?;
+				}
+				Ok::<_, DispatchError>(())
+			})?;
+			<T::Assets as fungibles::Mutate<_>>::transfer(
+				asset,
+				&from,
+				&dest,
+				total,
+				Preservation::Expendable,
+			)?;
+			Self::update_asset_lock(asset, &dest)?;
+			Self::index_schedule_end(&dest, &schedule);
+
+			Self::deposit_event(Event::AssetVestingScheduleAdded {
+				asset,
+				from,
+				to: dest,
+				schedule,
+			});
+			Ok(())
+		}
+
+		/// Claim vested funds of an asset
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Schedules that have fully vested are removed and their storage deposits returned.
+		///
+		/// - `asset`: The asset to claim
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::claim_asset(T::MaxVestingSchedules::get()))]
+		pub fn claim_asset(origin: OriginFor<T>, asset: T::AssetId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let now = Self::vesting_now(&who);
+			let tge = TgeBlock::<T>::get();
+			let mut completed = Vec::new();
+			AssetVestingSchedules::<T>::mutate_exists(asset, &who, |maybe_schedules| {
+				if let Some(schedules) = maybe_schedules {
+					schedules.retain(|schedule| {
+						let done = schedule
+							.anchored(tge)
+							.locked_amount::<T::BlockNumberToBalance>(now)
+							.is_zero();
+						if done {
+							completed.push(schedule.clone());
+						}
+						!done
+					});
+					if schedules.is_empty() {
+						*maybe_schedules = None;
+					}
+				}
+			});
+			if !AssetVestingSchedules::<T>::contains_key(asset, &who) {
+				VestingAssets::<T>::mutate(&who, |assets| assets.retain(|a| *a != asset));
+			}
+			for schedule in completed.iter() {
+				Self::release_deposit(schedule);
+			}
+
+			let previously_locked =
+				<T::AssetsFreezer as fungibles::InspectFreeze<_>>::balance_frozen(
+					asset,
+					&FreezeReason::Vesting.into(),
+					&who,
+				);
+			let locked = Self::update_asset_lock(asset, &who)?;
+
+			Self::deposit_event(Event::AssetClaimed {
+				asset,
+				who,
+				unlocked: previously_locked.saturating_sub(locked),
+				locked,
+			});
+			Ok(())
+		}
+
+		/// Set the minimum vesting amount of an asset
+		///
+		/// The dispatch origin for this call must be `AdminOrigin`.
+		///
+		/// - `asset`: The asset
+		/// - `amount`: The minimum, `None` to fall back to `MinVestedTransfer`
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::set_asset_min_vested_transfer())]
+		pub fn set_asset_min_vested_transfer(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			amount: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			AssetMinVestedTransfer::<T>::set(asset, amount);
+
+			Self::deposit_event(Event::AssetMinVestedTransferSet { asset, amount });
+			Ok(())
+		}
+
+		/// Set the token generation event block, once
+		///
+		/// The dispatch origin for this call must be `AdminOrigin`.
+		///
+		/// Schedules with `tge_relative` start `start` blocks after `block`. Until it is set,
+		/// they stay fully locked. `block` cannot be in the past, as it can't be corrected later.
+		///
+		/// - `block`: The block of the token generation event
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::set_tge_block())]
+		pub fn set_tge_block(origin: OriginFor<T>, block: BlockNumberFor<T>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(!TgeBlock::<T>::exists(), Error::<T>::TgeAlreadySet);
+			ensure!(
+				block >= T::BlockNumberProvider::current_block_number(),
+				Error::<T>::TgeBlockInPast
+			);
+			TgeBlock::<T>::put(block);
+
+			Self::deposit_event(Event::TgeBlockSet { block });
+			Ok(())
+		}
+
+		/// Speed up an account's vesting or release part of it at once
+		///
+		/// The dispatch origin for this call must be `AdminOrigin`.
+		///
+		/// Every schedule of `who` is recomputed with the same total, and the acceleration is
+		/// recorded in [`VestingAccelerations`] under the next number in [`AccelerationCount`].
+		///
+		/// - `who`: The account whose schedules are accelerated
+		/// - `acceleration`: How to accelerate them
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::accelerate_vesting(T::MaxVestingSchedules::get()))]
+		pub fn accelerate_vesting(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			acceleration: Acceleration,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(!FrozenSince::<T>::contains_key(&who), Error::<T>::AccountFrozen);
+			let valid = match acceleration {
+				Acceleration::SpeedUp(factor) => factor > 1,
+				Acceleration::VestNow(part) => !part.is_zero(),
+			};
+			ensure!(valid, Error::<T>::InvalidAcceleration);
+
+			let now = T::BlockNumberProvider::current_block_number();
+			let tge = TgeBlock::<T>::get();
+			let previously_locked = Self::locked_balance(&who);
+			let schedules = VestingSchedules::<T>::try_mutate(&who, |schedules| {
+				ensure!(!schedules.is_empty(), Error::<T>::NotVested);
+				for schedule in schedules.iter_mut() {
+					match acceleration {
+						Acceleration::SpeedUp(factor) => schedule.speed_up(factor, now, tge),
+						Acceleration::VestNow(part) => schedule.vest_now(part),
+					}
+				}
+				Ok::<_, DispatchError>(schedules.clone())
+			})?;
+			let locked = Self::update_lock(&who)?;
+			for schedule in schedules.iter() {
+				Self::index_schedule_end(&who, schedule);
+			}
+
+			let unlocked = previously_locked.saturating_sub(locked);
+			let count = AccelerationCount::<T>::get(&who);
+			let record = AccelerationRecord { at: now, acceleration, unlocked };
+			VestingAccelerations::<T>::insert(&who, count, record);
+			AccelerationCount::<T>::insert(&who, count.saturating_add(1));
+
+			Self::deposit_event(Event::VestingAccelerated { who, acceleration, unlocked });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -275,64 +1833,528 @@ pub mod pallet {
 		fn do_vested_transfer(
 			from: &T::AccountId,
 			to: &T::AccountId,
-			schedule: VestingScheduleOf<T>,
+			mut schedule: VestingScheduleOf<T>,
 		) -> DispatchResult {
-			// TODO: Implement the core vested transfer logic
-			// 1. Get current block number
-			// 2. Validate the schedule (period > 0, period_count > 0, valid start block)
-			// 3. Calculate total amount and validate minimum transfer
-			// 4. Check sender has enough balance
-			// 5. Transfer the tokens
-			// 6. Add schedule to storage
-			// 7. Update locks
-			// Hints:
-			// - Use frame_system::Pallet::<T>::block_number() for current block
-			// - Use T::Currency::transfer() for token transfer
-			// - Use VestingSchedules::<T>::try_mutate() to update storage
-			todo!()
-		}
-
-
-		/// Update the lock amount for an account
-		fn update_lock(who: &T::AccountId) -> Result<BalanceOf<T>, DispatchError> {
-			// TODO: Implement lock update logic
-			// 1. Get all vesting schedules for the account
-			// 2. Get current block number
-			// 3. Calculate total locked amount across all schedules
-			// 4. Set the lock using T::Currency::set_lock()
-			// 5. Return the total locked amount
-			// Hint: Use VESTING_ID as the lock identifier
-			todo!()
-		}
-
-		/// Calculate the total locked amount for a set of schedules
+			Self::ensure_accepted_grantor(from, to)?;
+			schedule.grantor = Some(from.clone());
+			let now = T::BlockNumberProvider::current_block_number();
+			let total = Self::validate_new_schedule(&schedule, now, T::MinVestedTransfer::get())?;
+			ensure!(
+				T::Currency::reducible_balance(from, Preservation::Expendable, Fortitude::Polite) >=
+					total.saturating_add(T::ScheduleDeposit::get()),
+				Error::<T>::InsufficientBalanceToLock
+			);
+
+			schedule.deposit = Self::hold_deposit(from)?;
+			VestingSchedules::<T>::try_mutate(to, |schedules| {
+				schedules
+					.try_push(schedule.clone())
+					.map_err(|_| Error::<T>::TooManyVestingSchedules)
+			})?;
+			T::Currency::transfer(from, to, total, Preservation::Expendable)?;
+			Self::update_lock(to)?;
+			Self::index_schedule_end(to, &schedule);
+
+			Self::deposit_event(Event::VestingScheduleAdded {
+				from: from.clone(),
+				to: to.clone(),
+				schedule,
+			});
+			Ok(())
+		}
+
+		/// Wrap the error of the grant at `index` of a batch in [`Error::BatchGrantFailed`]
+		///
+		/// Errors that are not about the grant itself, such as the sender's funds or deposit,
+		/// are returned unchanged.
+		fn batch_grant_error(index: usize, error: DispatchError) -> DispatchError {
+			let is = |e: Error<T>| error == e.into();
+			let reason = if is(Error::<T>::TooManyVestingSchedules) {
+				BatchGrantError::TooManyVestingSchedules
+			} else if is(Error::<T>::AmountLow) {
+				BatchGrantError::AmountLow
+			} else if is(Error::<T>::InvalidVestingStart) {
+				BatchGrantError::InvalidVestingStart
+			} else if is(Error::<T>::GrantorNotAccepted) {
+				BatchGrantError::GrantorNotAccepted
+			} else if is(Error::<T>::ZeroVestingPeriod) ||
+				is(Error::<T>::ZeroVestingPeriodCount) ||
+				is(Error::<T>::InvalidCliff) ||
+				is(Error::<T>::UnsortedTranches) ||
+				is(Error::<T>::InvalidMilestone)
+			{
+				BatchGrantError::InvalidSchedule
+			} else if let DispatchError::Module(_) = error {
+				return error
+			} else {
+				BatchGrantError::TransferFailed
+			};
+			Error::<T>::BatchGrantFailed { index: index.saturated_into(), reason }.into()
+		}
+
+		/// Ensure `to` accepts new schedules from `grantor`
+		fn ensure_accepted_grantor(grantor: &T::AccountId, to: &T::AccountId) -> DispatchResult {
+			if let Some(grantors) = AcceptedGrantors::<T>::get(to) {
+				ensure!(grantors.contains(grantor), Error::<T>::GrantorNotAccepted);
+			}
+			Ok(())
+		}
+
+		/// Check a schedule the way every new grant is checked, returning its total
+		fn validate_new_schedule(
+			schedule: &VestingScheduleOf<T>,
+			now: BlockNumberFor<T>,
+			min_vested_transfer: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let total = Self::ensure_valid_schedule(schedule)?;
+			ensure!(
+				schedule.anchored(TgeBlock::<T>::get()).is_valid_start_block(now),
+				Error::<T>::InvalidVestingStart
+			);
+			ensure!(total >= min_vested_transfer, Error::<T>::AmountLow);
+			Ok(total)
+		}
+
+		/// The linear schedule locking what `schedule_a` and `schedule_b` still lock at `now`
+		///
+		/// Both schedules must be linear: the remaining locks are then convex from the later start,
+		/// so the merged release never unlocks more than the two would together.
+		fn merge_pair(
+			schedule_a: &VestingScheduleOf<T>,
+			schedule_b: &VestingScheduleOf<T>,
+			now: BlockNumberFor<T>,
+		) -> Result<Option<VestingScheduleOf<T>>, DispatchError> {
+			let gated = |schedule: &VestingScheduleOf<T>| {
+				schedule.tge_relative || matches!(schedule.curve, VestingCurve::Milestones { .. })
+			};
+			ensure!(
+				schedule_a.grantor == schedule_b.grantor &&
+					schedule_a.revocable == schedule_b.revocable &&
+					!gated(schedule_a) && !gated(schedule_b),
+				Error::<T>::IncompatibleSchedules
+			);
+			let locked = schedule_a
+				.locked_amount::<T::BlockNumberToBalance>(now)
+				.checked_add(&schedule_b.locked_amount::<T::BlockNumberToBalance>(now))
+				.ok_or(Error::<T>::ArithmeticOverflow)?;
+			if locked.is_zero() {
+				return Ok(None);
+			}
+			let linear = |schedule: &VestingScheduleOf<T>| {
+				matches!(schedule.curve, VestingCurve::Linear { .. })
+			};
+			ensure!(linear(schedule_a) && linear(schedule_b), Error::<T>::IncompatibleSchedules);
+
+			let start = now.max(schedule_a.start).max(schedule_b.start);
+			let end = schedule_a
+				.end()
+				.zip(schedule_b.end())
+				.map(|(end_a, end_b)| end_a.max(end_b))
+				.ok_or(Error::<T>::ArithmeticOverflow)?;
+			let duration = end.saturating_sub(start).max(One::one());
+			Ok(Some(VestingSchedule {
+				start,
+				curve: VestingCurve::Linear { duration, total: locked },
+				grantor: schedule_a.grantor.clone(),
+				revocable: schedule_a.revocable,
+				tge_relative: false,
+				released_early: Zero::zero(),
+				deposit: schedule_b.deposit,
+			}))
+		}
+
+		/// Check the parameters shared by every way of creating a schedule, returning its total
+		fn ensure_valid_schedule(
+			schedule: &VestingScheduleOf<T>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			match &schedule.curve {
+				VestingCurve::Stepped { period, period_count, .. } => {
+					ensure!(!period.is_zero(), Error::<T>::ZeroVestingPeriod);
+					ensure!(*period_count > 0, Error::<T>::ZeroVestingPeriodCount);
+				},
+				VestingCurve::Cliff { cliff, duration, .. } => {
+					ensure!(!duration.is_zero(), Error::<T>::ZeroVestingPeriod);
+					ensure!(cliff <= duration, Error::<T>::InvalidCliff);
+				},
+				VestingCurve::Linear { duration, .. } => {
+					ensure!(!duration.is_zero(), Error::<T>::ZeroVestingPeriod);
+				},
+				VestingCurve::Tranches(tranches) => {
+					ensure!(!tranches.is_empty(), Error::<T>::ZeroVestingPeriodCount);
+					ensure!(
+						tranches.windows(2).all(|pair| pair[0].0 < pair[1].0),
+						Error::<T>::UnsortedTranches
+					);
+				},
+				VestingCurve::Milestones { milestone_count, released, .. } => {
+					ensure!(*milestone_count > 0, Error::<T>::ZeroVestingPeriodCount);
+					ensure!(*milestone_count <= <MaxMilestones as Get<u32>>::get(), Error::<T>::InvalidMilestone);
+					ensure!(
+						released.windows(2).all(|pair| pair[0] < pair[1]) &&
+							released.last().is_none_or(|last| last < milestone_count),
+						Error::<T>::InvalidMilestone
+					);
+				},
+			}
+			Ok(schedule.total_amount().ok_or(Error::<T>::ArithmeticOverflow)?)
+		}
+
+
+		/// Update the frozen amount for an account, thawing it once nothing is locked
+		pub(crate) fn update_lock(who: &T::AccountId) -> Result<BalanceOf<T>, DispatchError> {
+			let schedules = VestingSchedules::<T>::get(who);
+			let total_locked =
+				Self::calculate_total_locked_amount(&schedules, Self::vesting_now(who))?;
+
+			T::Currency::set_freeze(&FreezeReason::Vesting.into(), who, total_locked)?;
+			Ok(total_locked)
+		}
+
+		/// The minimum vesting amount of `asset`
+		pub fn min_vested_transfer(asset: T::AssetId) -> BalanceOf<T> {
+			AssetMinVestedTransfer::<T>::get(asset).unwrap_or_else(T::MinVestedTransfer::get)
+		}
+
+		/// Update the frozen amount of `asset` for an account, thawing it once nothing is locked
+		pub(crate) fn update_asset_lock(
+			asset: T::AssetId,
+			who: &T::AccountId,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let schedules = AssetVestingSchedules::<T>::get(asset, who);
+			let total_locked =
+				Self::calculate_total_locked_amount(&schedules, Self::vesting_now(who))?;
+
+			<T::AssetsFreezer as fungibles::MutateFreeze<_>>::set_freeze(
+				asset,
+				&FreezeReason::Vesting.into(),
+				who,
+				total_locked,
+			)?;
+			Ok(total_locked)
+		}
+
+		/// Put the storage deposit of a new schedule on hold from its grantor
+		///
+		/// Returns the amount held.
+		pub(crate) fn hold_deposit(grantor: &T::AccountId) -> Result<BalanceOf<T>, DispatchError> {
+			let deposit = T::ScheduleDeposit::get();
+			if !deposit.is_zero() {
+				T::Currency::hold(&HoldReason::ScheduleDeposit.into(), grantor, deposit)?;
+			}
+			Ok(deposit)
+		}
+
+		/// Return the storage deposit held for `schedule` to its grantor, if any
+		pub(crate) fn release_deposit(schedule: &VestingScheduleOf<T>) {
+			let Some(grantor) = schedule.grantor.as_ref() else { return };
+			if schedule.deposit.is_zero() {
+				return;
+			}
+
+			if let Err(e) = T::Currency::release(
+				&HoldReason::ScheduleDeposit.into(),
+				grantor,
+				schedule.deposit,
+				Precision::BestEffort,
+			) {
+				defensive!("Failed to release a vesting schedule deposit", e);
+			}
+		}
+
+		/// The key `schedule` is indexed at, and whether it is in [`TgeScheduleEnds`] rather than
+		/// [`ScheduleEnds`]
+		fn schedule_end_entry(schedule: &VestingScheduleOf<T>) -> Option<(ScheduleEndKey, bool)> {
+			if schedule.tge_relative {
+				// Measured from a TGE at zero, the end is its offset from the TGE.
+				let end = schedule.anchored(Some(Zero::zero())).end()?;
+				Some((schedule_end_key(end), true))
+			} else {
+				Some((schedule_end_key(schedule.end()?), false))
+			}
+		}
+
+		/// Record `who` in [`ScheduleEnds`], or [`TgeScheduleEnds`] for a TGE-relative schedule,
+		/// so `on_idle` prunes `schedule` once it has fully vested
+		pub(crate) fn index_schedule_end(who: &T::AccountId, schedule: &VestingScheduleOf<T>) {
+			match Self::schedule_end_entry(schedule) {
+				Some((key, true)) => TgeScheduleEnds::<T>::insert(key, who, ()),
+				Some((key, false)) => ScheduleEnds::<T>::insert(key, who, ()),
+				None => {},
+			}
+		}
+
+		/// Remove the index entry of `schedule`, which `who` no longer holds, unless another of
+		/// `who`'s schedules ends at the same key
+		fn unindex_schedule_end(who: &T::AccountId, schedule: &VestingScheduleOf<T>) {
+			let Some(entry) = Self::schedule_end_entry(schedule) else { return };
+			let shared = VestingSchedules::<T>::get(who)
+				.into_iter()
+				.chain(
+					VestingAssets::<T>::get(who)
+						.into_iter()
+						.flat_map(|asset| AssetVestingSchedules::<T>::get(asset, who)),
+				)
+				.any(|other| Self::schedule_end_entry(&other) == Some(entry));
+			match entry {
+				(key, true) if !shared => TgeScheduleEnds::<T>::remove(key, who),
+				(key, false) if !shared => ScheduleEnds::<T>::remove(key, who),
+				_ => {},
+			}
+		}
+
+		/// Prune the accounts in [`ScheduleEnds`] and [`TgeScheduleEnds`] whose schedules have
+		/// ended by now, earliest first, within `remaining_weight`
+		///
+		/// Returns the weight used.
+		pub(crate) fn cleanup_completed(remaining_weight: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(remaining_weight);
+			let now = T::BlockNumberProvider::current_block_number();
+			let due = schedule_end_key(now);
+			let tge_due = TgeBlock::<T>::get()
+				.filter(|tge| *tge <= now)
+				.map(|tge| schedule_end_key(now.saturating_sub(tge)));
+			let prune_weight = T::WeightInfo::cleanup_account(T::MaxVestingSchedules::get())
+				.saturating_mul(T::MaxVestingAssets::get().saturating_add(1).into());
+
+			while meter.try_consume(T::WeightInfo::cleanup_step()).is_ok() {
+				// Both indexes are ordered by end, so their first entries are the next ones due.
+				let next = ScheduleEnds::<T>::iter()
+					.next()
+					.filter(|(end, _, _)| *end <= due)
+					.map(|(end, who, _)| (end, who, false))
+					.or_else(|| {
+						let tge_due = tge_due?;
+						TgeScheduleEnds::<T>::iter()
+							.next()
+							.filter(|(end, _, _)| *end <= tge_due)
+							.map(|(end, who, _)| (end, who, true))
+					});
+				let Some((end, who, tge_relative)) = next else {
+					break;
+				};
+				if meter.try_consume(prune_weight).is_err() {
+					break;
+				}
+				if tge_relative {
+					TgeScheduleEnds::<T>::remove(end, &who);
+				} else {
+					ScheduleEnds::<T>::remove(end, &who);
+				}
+				Self::prune_completed(&who, now);
+			}
+
+			meter.consumed()
+		}
+
+		/// Drop the schedules of `who`, in `Currency` and in `Assets`, that have fully vested by
+		/// `now` and release what they froze
+		pub(crate) fn prune_completed(who: &T::AccountId, now: BlockNumberFor<T>) {
+			// Frozen accounts are re-indexed when they are unfrozen.
+			let now = FrozenSince::<T>::get(who).map_or(now, |since| since.min(now));
+			let previously_locked = T::Currency::balance_frozen(&FreezeReason::Vesting.into(), who);
+			let tge = TgeBlock::<T>::get();
+			let mut completed = Vec::new();
+			VestingSchedules::<T>::mutate_exists(who, |maybe_schedules| {
+				Self::take_completed(maybe_schedules, tge, now, &mut completed)
+			});
+			match Self::update_lock(who) {
+				Ok(locked) if locked.is_zero() && !previously_locked.is_zero() =>
+					Self::deposit_event(Event::VestingCompleted { who: who.clone() }),
+				Ok(_) => {},
+				Err(e) => {
+					defensive!("Failed to update the lock of a completed schedule", e);
+				},
+			}
+
+			for asset in VestingAssets::<T>::get(who) {
+				AssetVestingSchedules::<T>::mutate_exists(asset, who, |maybe_schedules| {
+					Self::take_completed(maybe_schedules, tge, now, &mut completed)
+				});
+				if !AssetVestingSchedules::<T>::contains_key(asset, who) {
+					VestingAssets::<T>::mutate(who, |assets| assets.retain(|a| *a != asset));
+				}
+				if let Err(e) = Self::update_asset_lock(asset, who) {
+					defensive!("Failed to update the asset lock of a completed schedule", e);
+				}
+			}
+
+			for schedule in completed.iter() {
+				Self::release_deposit(schedule);
+			}
+		}
+
+		/// Move the schedules that have fully vested by `now` out of `maybe_schedules` into
+		/// `completed`, removing the entry once none are left
+		fn take_completed(
+			maybe_schedules: &mut Option<BoundedVec<VestingScheduleOf<T>, T::MaxVestingSchedules>>,
+			tge: Option<BlockNumberFor<T>>,
+			now: BlockNumberFor<T>,
+			completed: &mut Vec<VestingScheduleOf<T>>,
+		) {
+			if let Some(schedules) = maybe_schedules {
+				schedules.retain(|schedule| {
+					let done =
+						schedule.anchored(tge).locked_amount::<T::BlockNumberToBalance>(now).is_zero();
+					if done {
+						completed.push(schedule.clone());
+					}
+					!done
+				});
+				if schedules.is_empty() {
+					*maybe_schedules = None;
+				}
+			}
+		}
+
+		/// Push `schedules` back by the time they spent frozen from `since` to `now`
+		fn extend_frozen(
+			schedules: &mut [VestingScheduleOf<T>],
+			since: BlockNumberFor<T>,
+			now: BlockNumberFor<T>,
+		) {
+			let tge = TgeBlock::<T>::get();
+			for schedule in schedules.iter_mut() {
+				// Only the frozen time after the schedule started held it back.
+				let anchored = schedule.anchored(tge);
+				if anchored.tge_relative {
+					continue;
+				}
+				let paused = now.saturating_sub(since.max(anchored.start));
+				schedule.start = schedule.start.saturating_add(paused);
+			}
+		}
+
+		/// The time `who`'s schedules are measured at: the current block, or the block their
+		/// vesting was frozen at
+		pub(crate) fn vesting_now(who: &T::AccountId) -> BlockNumberFor<T> {
+			let now = T::BlockNumberProvider::current_block_number();
+			FrozenSince::<T>::get(who).map_or(now, |since| since.min(now))
+		}
+
+		/// Calculate the total locked amount at `now` for a set of schedules
 		fn calculate_total_locked_amount(
 			schedules: &BoundedVec<VestingScheduleOf<T>, T::MaxVestingSchedules>,
+			now: BlockNumberFor<T>,
 		) -> Result<BalanceOf<T>, DispatchError> {
-			// TODO: Calculate total locked amount
-			// 1. Get current block number
-			// 2. Iterate through schedules and sum locked amounts
-			// 3. Use checked arithmetic to avoid overflow
-			// Hint: Use try_fold() for safe accumulation
-			todo!()
+			let tge = TgeBlock::<T>::get();
+			schedules
+				.iter()
+				.try_fold(Zero::zero(), |total: BalanceOf<T>, schedule| {
+					total.checked_add(
+						&schedule.anchored(tge).locked_amount::<T::BlockNumberToBalance>(now),
+					)
+				})
+				.ok_or_else(|| Error::<T>::ArithmeticOverflow.into())
 		}
 
 		/// Get vested amount for an account
 		pub fn vested_balance(who: &T::AccountId) -> BalanceOf<T> {
-			// TODO: Calculate total vested balance
-			// 1. Get all schedules for the account
-			// 2. Sum up vested amounts from all schedules
-			// Hint: Use fold() to accumulate vested amounts
-			todo!()
+			Self::vested_in(who, &VestingSchedules::<T>::get(who))
 		}
 
 		/// Get locked balance for an account
 		pub fn locked_balance(who: &T::AccountId) -> BalanceOf<T> {
-			// TODO: Calculate total locked balance
-			// 1. Get all schedules for the account
-			// 2. Sum up locked amounts from all schedules
-			// Hint: Similar to vested_balance but use locked_amount()
-			todo!()
+			Self::locked_in(who, &VestingSchedules::<T>::get(who))
+		}
+
+		/// Get vested amount of `asset` for an account
+		pub fn asset_vested_balance(asset: T::AssetId, who: &T::AccountId) -> BalanceOf<T> {
+			Self::vested_in(who, &AssetVestingSchedules::<T>::get(asset, who))
+		}
+
+		/// Get locked balance of `asset` for an account
+		pub fn asset_locked_balance(asset: T::AssetId, who: &T::AccountId) -> BalanceOf<T> {
+			Self::locked_in(who, &AssetVestingSchedules::<T>::get(asset, who))
+		}
+
+		/// The amount `schedules` of `who` have vested, in the native currency or an asset
+		fn vested_in(who: &T::AccountId, schedules: &[VestingScheduleOf<T>]) -> BalanceOf<T> {
+			let now = Self::vesting_now(who);
+			let tge = TgeBlock::<T>::get();
+			schedules.iter().fold(Zero::zero(), |total: BalanceOf<T>, schedule| {
+				total.saturating_add(schedule.anchored(tge).vested_amount::<T::BlockNumberToBalance>(now))
+			})
+		}
+
+		/// The amount `schedules` of `who` still lock, in the native currency or an asset
+		fn locked_in(who: &T::AccountId, schedules: &[VestingScheduleOf<T>]) -> BalanceOf<T> {
+			let now = Self::vesting_now(who);
+			let tge = TgeBlock::<T>::get();
+			schedules.iter().fold(Zero::zero(), |total: BalanceOf<T>, schedule| {
+				total.saturating_add(schedule.anchored(tge).locked_amount::<T::BlockNumberToBalance>(now))
+			})
+		}
+
+		/// Get the amount `claim` would unlock for an account right now
+		pub fn claimable_balance(who: &T::AccountId) -> BalanceOf<T> {
+			T::Currency::balance_frozen(&FreezeReason::Vesting.into(), who)
+				.saturating_sub(Self::locked_balance(who))
+		}
+
+		/// Get the next block at which more of an account's vesting unlocks, `None` while it is
+		/// frozen
+		pub fn next_unlock(who: &T::AccountId) -> Option<BlockNumberFor<T>> {
+			if FrozenSince::<T>::contains_key(who) {
+				return None;
+			}
+			let now = T::BlockNumberProvider::current_block_number();
+			let tge = TgeBlock::<T>::get();
+			VestingSchedules::<T>::get(who)
+				.iter()
+				.filter_map(|schedule| schedule.anchored(tge).next_unlock(now))
+				.min()
+		}
+
+		/// Get the projected unlocks of an account, see [`Self::merge_unlocks`], empty while its
+		/// vesting is frozen
+		pub fn unlock_calendar(who: &T::AccountId, max_entries: u32) -> UnlockCalendarOf<T> {
+			if FrozenSince::<T>::contains_key(who) {
+				return BoundedVec::default();
+			}
+			let now = T::BlockNumberProvider::current_block_number();
+			let tge = TgeBlock::<T>::get();
+			let schedules: Vec<_> = VestingSchedules::<T>::get(who)
+				.iter()
+				.map(|schedule| schedule.anchored(tge))
+				.collect();
+			Self::merge_unlocks(&schedules, now, max_entries)
+		}
+
+		/// Merge `schedules` into the blocks after `now` at which more of them unlocks, in order,
+		/// as `(block, unlocked, cumulative)` with `cumulative` everything vested by `block`
+		///
+		/// Linear releases are summed up into the entry of the block they end at, or of an earlier
+		/// step of another schedule. At most `max_entries` blocks are looked at, and never more
+		/// than [`MaxCalendarEntries`].
+		pub fn merge_unlocks(
+			schedules: &[VestingScheduleOf<T>],
+			now: BlockNumberFor<T>,
+			max_entries: u32,
+		) -> UnlockCalendarOf<T> {
+			let vested_at = |at: BlockNumberFor<T>| {
+				schedules.iter().fold(Zero::zero(), |total: BalanceOf<T>, schedule| {
+					total.saturating_add(schedule.vested_amount::<T::BlockNumberToBalance>(at))
+				})
+			};
+
+			let mut calendar = Vec::new();
+			let mut at = now;
+			let mut cumulative = vested_at(now);
+			for _ in 0..max_entries.min(<MaxCalendarEntries as Get<u32>>::get()) {
+				let Some(next) =
+					schedules.iter().filter_map(|schedule| schedule.next_release_point(at)).min()
+				else {
+					break;
+				};
+				let vested = vested_at(next);
+				let unlocked = vested.saturating_sub(cumulative);
+				if !unlocked.is_zero() {
+					calendar.push((next, unlocked, vested));
+				}
+				cumulative = vested;
+				at = next;
+			}
+			BoundedVec::truncate_from(calendar)
 		}
 	}
 }
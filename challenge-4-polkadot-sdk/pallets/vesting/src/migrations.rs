@@ -0,0 +1,265 @@
+//! Storage migrations for the vesting pallet.
+
+use super::*;
+
+/// The identifier prefix of the vesting pallet's multi-block migrations.
+pub const PALLET_MIGRATIONS_ID: &[u8; 14] = b"pallet-vesting";
+
+pub mod v1 {
+	use super::*;
+	use polkadot_sdk::frame_support::{
+		defensive,
+		migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+		traits::{GetStorageVersion, InspectLockableCurrency, LockableCurrency},
+		weights::WeightMeter,
+	};
+
+	/// Replaces the `vesting ` lock of every vesting account with a [`FreezeReason::Vesting`]
+	/// freeze of the same amount, one account per step.
+	///
+	/// `OldCurrency` is the `LockableCurrency` that held the locks, usually the same balances
+	/// pallet that now backs `Config::Currency`. Register it in
+	/// `pallet_migrations::Config::Migrations`, before [`v2::LazyMigrationV1ToV2`]. It bumps the
+	/// storage version to 1 once the last account is migrated.
+	pub struct LazyMigrateLocksToFreezes<T, OldCurrency>(PhantomData<(T, OldCurrency)>);
+
+	impl<T, OldCurrency> LazyMigrateLocksToFreezes<T, OldCurrency>
+	where
+		T: Config,
+	{
+		/// The weight of migrating one account: the schedules key, the lock and the account are
+		/// read, and the lock, the freeze and the account are written.
+		pub fn step_weight() -> Weight {
+			T::DbWeight::get().reads_writes(3, 3)
+		}
+	}
+
+	impl<T, OldCurrency> SteppedMigration for LazyMigrateLocksToFreezes<T, OldCurrency>
+	where
+		T: Config,
+		OldCurrency: LockableCurrency<T::AccountId, Balance = BalanceOf<T>>
+			+ InspectLockableCurrency<T::AccountId>,
+	{
+		type Cursor = T::AccountId;
+		type Identifier = MigrationId<14>;
+
+		fn id() -> Self::Identifier {
+			MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 0, version_to: 1 }
+		}
+
+		fn step(
+			mut cursor: Option<Self::Cursor>,
+			meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			if Pallet::<T>::on_chain_storage_version() != 0 {
+				return Ok(None);
+			}
+
+			let required = Self::step_weight();
+			if meter.remaining().any_lt(required) {
+				return Err(SteppedMigrationError::InsufficientWeight { required });
+			}
+
+			while meter.try_consume(required).is_ok() {
+				// Only the keys are read, so the layout of the schedules does not matter.
+				let mut iter = if let Some(last) = cursor.as_ref() {
+					VestingSchedules::<T>::iter_keys_from(VestingSchedules::<T>::hashed_key_for(last))
+				} else {
+					VestingSchedules::<T>::iter_keys()
+				};
+
+				let Some(who) = iter.next() else {
+					StorageVersion::new(1).put::<Pallet<T>>();
+					return Ok(None);
+				};
+				let locked = OldCurrency::balance_locked(VESTING_ID, &who);
+				OldCurrency::remove_lock(VESTING_ID, &who);
+				if let Err(e) = T::Currency::set_freeze(&FreezeReason::Vesting.into(), &who, locked) {
+					defensive!("Failed to freeze the previously locked vesting balance", e);
+				}
+				cursor = Some(who);
+			}
+
+			Ok(cursor)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, polkadot_sdk::sp_runtime::TryRuntimeError> {
+			let locks: Vec<(T::AccountId, BalanceOf<T>)> = VestingSchedules::<T>::iter_keys()
+				.map(|who| {
+					let locked = OldCurrency::balance_locked(VESTING_ID, &who);
+					(who, locked)
+				})
+				.collect();
+			Ok(locks.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), polkadot_sdk::sp_runtime::TryRuntimeError> {
+			let locks = Vec::<(T::AccountId, BalanceOf<T>)>::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the pre-upgrade locks")?;
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 1,
+				"Storage version was not bumped to 1"
+			);
+			for (who, locked) in locks {
+				ensure!(
+					OldCurrency::balance_locked(VESTING_ID, &who).is_zero(),
+					"Vesting lock was not removed"
+				);
+				ensure!(
+					T::Currency::balance_frozen(&FreezeReason::Vesting.into(), &who) == locked,
+					"Vesting freeze does not match the previous lock"
+				);
+			}
+			Ok(())
+		}
+	}
+}
+
+pub mod v2 {
+	use super::*;
+	use polkadot_sdk::frame_support::{
+		migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+		storage_alias,
+		traits::GetStorageVersion,
+		weights::WeightMeter,
+	};
+
+	/// A vesting schedule as stored at storage version 1, before release curves and grants.
+	#[derive(Clone, Encode, Decode, MaxEncodedLen, TypeInfo, Debug, PartialEq)]
+	pub struct OldVestingSchedule<BlockNumber, Balance> {
+		/// The block number when the vesting schedule starts
+		pub start: BlockNumber,
+		/// The number of blocks between vesting periods
+		pub period: BlockNumber,
+		/// The number of vesting periods
+		pub period_count: u32,
+		/// The amount of balance that will be vested per period
+		pub per_period: Balance,
+	}
+
+	impl<BlockNumber, Balance: Zero> OldVestingSchedule<BlockNumber, Balance> {
+		/// The same schedule as a [`VestingCurve::Stepped`] schedule without a grantor.
+		pub fn upgrade<AccountId>(self) -> VestingSchedule<AccountId, BlockNumber, Balance> {
+			VestingSchedule {
+				start: self.start,
+				curve: VestingCurve::Stepped {
+					period: self.period,
+					period_count: self.period_count,
+					per_period: self.per_period,
+				},
+				grantor: None,
+				revocable: false,
+				tge_relative: false,
+				released_early: Zero::zero(),
+				deposit: Zero::zero(),
+			}
+		}
+	}
+
+	pub mod old {
+		use super::*;
+
+		/// `VestingSchedules` as stored at storage version 1.
+		#[storage_alias]
+		pub type VestingSchedules<T: Config> = StorageMap<
+			Pallet<T>,
+			Blake2_128Concat,
+			<T as frame_system::Config>::AccountId,
+			BoundedVec<
+				OldVestingSchedule<BlockNumberFor<T>, BalanceOf<T>>,
+				<T as Config>::MaxVestingSchedules,
+			>,
+			ValueQuery,
+		>;
+	}
+
+	/// Rewrites every account's schedules from the version 1 layout into [`VestingSchedule`], one
+	/// account per step, and indexes them for `on_idle` cleanup.
+	///
+	/// Register it in `pallet_migrations::Config::Migrations`. It bumps the storage version to 2
+	/// once the last account is migrated.
+	pub struct LazyMigrationV1ToV2<T>(PhantomData<T>);
+
+	impl<T: Config> SteppedMigration for LazyMigrationV1ToV2<T> {
+		type Cursor = T::AccountId;
+		type Identifier = MigrationId<14>;
+
+		fn id() -> Self::Identifier {
+			MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 1, version_to: 2 }
+		}
+
+		fn step(
+			mut cursor: Option<Self::Cursor>,
+			meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			if Pallet::<T>::on_chain_storage_version() != 1 {
+				return Ok(None);
+			}
+
+			let required = T::WeightInfo::migrate_v1_to_v2_step(T::MaxVestingSchedules::get());
+			if meter.remaining().any_lt(required) {
+				return Err(SteppedMigrationError::InsufficientWeight { required });
+			}
+
+			while meter.try_consume(required).is_ok() {
+				let mut iter = if let Some(last) = cursor.as_ref() {
+					let last = old::VestingSchedules::<T>::hashed_key_for(last);
+					old::VestingSchedules::<T>::iter_from(last)
+				} else {
+					old::VestingSchedules::<T>::iter()
+				};
+
+				let Some((who, old_schedules)) = iter.next() else {
+					StorageVersion::new(2).put::<Pallet<T>>();
+					return Ok(None);
+				};
+				let schedules: BoundedVec<VestingScheduleOf<T>, T::MaxVestingSchedules> =
+					BoundedVec::truncate_from(
+						old_schedules.into_iter().map(OldVestingSchedule::upgrade).collect(),
+					);
+				for schedule in schedules.iter() {
+					Pallet::<T>::index_schedule_end(&who, schedule);
+				}
+				VestingSchedules::<T>::insert(&who, schedules);
+				cursor = Some(who);
+			}
+
+			Ok(cursor)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, polkadot_sdk::sp_runtime::TryRuntimeError> {
+			let schedules: Vec<(T::AccountId, Vec<VestingScheduleOf<T>>)> =
+				old::VestingSchedules::<T>::iter()
+					.map(|(who, schedules)| {
+						(who, schedules.into_iter().map(OldVestingSchedule::upgrade).collect())
+					})
+					.collect();
+			Ok(schedules.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), polkadot_sdk::sp_runtime::TryRuntimeError> {
+			let expected = Vec::<(T::AccountId, Vec<VestingScheduleOf<T>>)>::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the pre-upgrade schedules")?;
+
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 2,
+				"Storage version was not bumped to 2"
+			);
+			ensure!(
+				VestingSchedules::<T>::iter_keys().count() == expected.len(),
+				"Number of vesting accounts changed"
+			);
+			for (who, schedules) in expected {
+				ensure!(
+					VestingSchedules::<T>::get(&who).into_inner() == schedules,
+					"Vesting schedules were not migrated as expected"
+				);
+			}
+			Ok(())
+		}
+	}
+}
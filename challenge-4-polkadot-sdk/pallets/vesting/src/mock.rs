@@ -4,9 +4,9 @@
 
 use super::*;
 use polkadot_sdk::{frame_support::{
-	construct_runtime, derive_impl, parameter_types,
-	traits::{ConstU32, ConstU64, EnsureOrigin},
-}, sp_runtime::traits::ConvertInto};
+	construct_runtime, derive_impl, ord_parameter_types, parameter_types,
+	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, EitherOfDiverse, VariantCountOf},
+}, frame_system::{EnsureRoot, EnsureSigned, EnsureSignedBy}, sp_runtime::traits::ConvertInto};
 
 use polkadot_sdk::{
 	polkadot_sdk_frame::runtime::prelude::*,
@@ -31,7 +31,7 @@ impl polkadot_sdk::frame_system::Config for Runtime {
 type Balance = u64;
 
 impl pallet_balances::Config for Runtime {
-    type RuntimeEvent = RuntimeEvent;
+	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
 	type DustRemoval = ();
 	type ExistentialDeposit = ConstU64<1>;
@@ -42,18 +42,70 @@ impl pallet_balances::Config for Runtime {
 	type WeightInfo = ();
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
-	type FreezeIdentifier = [u8; 8];
-	type MaxFreezes = ();
+	type FreezeIdentifier = RuntimeFreezeReason;
+	type MaxFreezes = VariantCountOf<RuntimeFreezeReason>;
 	type DoneSlashHandler = ();
 }
+#[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
+impl pallet_assets::Config for Runtime {
+	type Balance = Balance;
+	type Currency = PalletBalances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type Freezer = AssetsFreezer;
+}
+
+impl pallet_assets_freezer::Config for Runtime {
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeEvent = RuntimeEvent;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct VestingBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<u32> for VestingBenchmarkHelper {
+	fn create_asset(index: u32) -> u32 {
+		let asset = ASSET + index;
+		if !<Assets as fungibles::Inspect<AccountId>>::asset_exists(asset) {
+			Assets::force_create(RuntimeOrigin::root(), asset, ALICE, true, 1)
+				.expect("The benchmark asset can be created");
+		}
+		asset
+	}
+}
+
+parameter_types! {
+	pub static ScheduleDeposit: u64 = 0;
+	pub static ExtendFrozenVesting: bool = false;
+}
 
+ord_parameter_types! {
+	pub const VestingAdmin: AccountId = CHARLIE;
+}
 
 impl Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type Currency = PalletBalances;
+	type AssetId = u32;
+	type Assets = Assets;
+	type AssetsFreezer = AssetsFreezer;
+	type BlockNumberProvider = System;
 	type MinVestedTransfer = ConstU64<5>;
 	type MaxVestingSchedules = ConstU32<2>;
-    type BlockNumberToBalance = ConvertInto;
-
+	type MaxVestingAssets = ConstU32<2>;
+	type BlockNumberToBalance = ConvertInto;
+	type MaxBatchSize = ConstU32<3>;
+	type ScheduleDeposit = ScheduleDeposit;
+	type ExtendFrozenVesting = ExtendFrozenVesting;
+	type MaxAcceptedGrantors = ConstU32<2>;
+	type AdminOrigin = EitherOfDiverse<EnsureRoot<AccountId>, EnsureSignedBy<VestingAdmin, AccountId>>;
+	type MilestoneOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = VestingBenchmarkHelper;
 }
 
 type Block = frame_system::mocking::MockBlock<Runtime>;
@@ -63,6 +115,8 @@ construct_runtime!(
 		System: frame_system,
 		Vesting: vesting,
 		PalletBalances: pallet_balances,
+		Assets: pallet_assets,
+		AssetsFreezer: pallet_assets_freezer,
 	}
 );
 
@@ -73,11 +127,20 @@ pub const CHARLIE: AccountId = 3;
 pub const ALICE_BALANCE: u64 = 100;
 pub const CHARLIE_BALANCE: u64 = 50;
 
+/// An asset ALICE holds `ALICE_BALANCE` of
+pub const ASSET: u32 = 1;
+
 #[derive(Default)]
 pub struct ExtBuilder;
 
 impl ExtBuilder {
 	pub fn build() -> sp_io::TestExternalities {
+		Self::build_with_vesting(vec![])
+	}
+
+	pub fn build_with_vesting(
+		vesting: Vec<(AccountId, VestingScheduleOf<Runtime>)>,
+	) -> sp_io::TestExternalities {
 		let mut t = frame_system::GenesisConfig::<Runtime>::default()
 			.build_storage()
 			.unwrap();
@@ -89,6 +152,15 @@ impl ExtBuilder {
 		.assimilate_storage(&mut t)
 		.unwrap();
 
+		pallet_assets::GenesisConfig::<Runtime> {
+			assets: vec![(ASSET, ALICE, true, 1)],
+			accounts: vec![(ASSET, ALICE, ALICE_BALANCE)],
+			..Default::default()
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		vesting::GenesisConfig::<Runtime> { vesting }.assimilate_storage(&mut t).unwrap();
 
 		t.into()
 	}
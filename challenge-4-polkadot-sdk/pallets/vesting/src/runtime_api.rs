@@ -0,0 +1,49 @@
+//! Runtime API for querying the vesting state of accounts.
+
+use super::VestingSchedule;
+use codec::Codec;
+use polkadot_sdk::sp_std::vec::Vec;
+
+polkadot_sdk::sp_api::decl_runtime_apis! {
+	/// Read-only access to the vesting schedules and balances of an account
+	///
+	/// Methods added after the first version carry the `api_version` that introduced them.
+	pub trait VestingApi<AccountId, BlockNumber, Balance, AssetId> where
+		AccountId: Codec,
+		BlockNumber: Codec,
+		Balance: Codec,
+		AssetId: Codec,
+	{
+		/// The vesting schedules of `who`
+		fn vesting_schedules(who: AccountId) -> Vec<VestingSchedule<AccountId, BlockNumber, Balance>>;
+
+		/// The amount that has vested for `who` across all schedules
+		fn vested_balance(who: AccountId) -> Balance;
+
+		/// The amount that is still locked for `who` across all schedules
+		fn locked_balance(who: AccountId) -> Balance;
+
+		/// The amount `claim` would unlock for `who` right now
+		fn claimable_balance(who: AccountId) -> Balance;
+
+		/// The next block at which more of `who`'s vesting unlocks, `None` if nothing is left
+		fn next_unlock(who: AccountId) -> Option<BlockNumber>;
+
+		/// The coming unlocks of `who` as `(block, unlocked, cumulative)`, in block order and at
+		/// most `max_entries` of them, capped at [`MaxCalendarEntries`](super::MaxCalendarEntries)
+		#[api_version(2)]
+		fn unlock_calendar(who: AccountId, max_entries: u32) -> Vec<(BlockNumber, Balance, Balance)>;
+
+		/// The vesting schedules of `who` in `asset`
+		#[api_version(3)]
+		fn asset_vesting_schedules(asset: AssetId, who: AccountId) -> Vec<VestingSchedule<AccountId, BlockNumber, Balance>>;
+
+		/// The amount of `asset` that has vested for `who` across all schedules
+		#[api_version(3)]
+		fn asset_vested_balance(asset: AssetId, who: AccountId) -> Balance;
+
+		/// The amount of `asset` that is still locked for `who` across all schedules
+		#[api_version(3)]
+		fn asset_locked_balance(asset: AssetId, who: AccountId) -> Balance;
+	}
+}
@@ -2,59 +2,1507 @@
 
 use super::*;
 use polkadot_sdk::frame_support::{assert_noop, assert_ok};
-use polkadot_sdk::pallet_balances::{BalanceLock, Reasons};
+use polkadot_sdk::frame_support::traits::{
+	fungible::{InspectFreeze, InspectHold},
+	fungibles, GetStorageVersion, LockableCurrency, StorageVersion,
+	WithdrawReasons,
+};
+use polkadot_sdk::sp_runtime::{traits::ConvertInto, DispatchError};
 use mock::*;
 
+fn with_curve(start: u64, curve: VestingCurve<u64, u64>) -> VestingScheduleOf<Runtime> {
+	VestingSchedule {
+		start,
+		curve,
+		grantor: None,
+		revocable: false,
+		tge_relative: false,
+		released_early: 0,
+		deposit: 0,
+	}
+}
 
-// HINTS:
-// - Set block number in mock tests : System::set_block_number(<number>);
-// - Get signed origin from the caller: RuntimeOrigin::signed(ALICE)/ RuntimeOrigin::signed(BOB)
-// - Call vested_transfer with the origin, destination, and schedule : Vesting::vested_transfer(origin, dest, schedule)
-// - Get the vesting schedules for an account: Vesting::vesting_schedules(&<account>)
-// - Get error : Error::<Runtime>::ZeroVestingPeriod / Error::<Runtime>::ZeroVestingPeriodCount
-// - Get the balance of an account: PalletBalances::free_balance(&<account>)
-// - Get locked balance of an account: Vesting::locked(&<account>)
-// - Transfer balance : PalletBalances::transfer(&<account>, &<account>, <amount>, <reason>)
+fn stepped(start: u64, period: u64, period_count: u32, per_period: u64) -> VestingScheduleOf<Runtime> {
+	with_curve(start, VestingCurve::Stepped { period, period_count, per_period })
+}
 
+/// `schedule` as stored after ALICE granted it through `vested_transfer`
+fn from_alice(schedule: VestingScheduleOf<Runtime>) -> VestingScheduleOf<Runtime> {
+	VestingSchedule { grantor: Some(ALICE), ..schedule }
+}
+
+fn frozen(who: AccountId) -> u64 {
+	PalletBalances::balance_frozen(&FreezeReason::Vesting.into(), &who)
+}
 
 #[test]
 fn vested_transfer_works() {
 	ExtBuilder::build().execute_with(|| {
-		// TODO: check if the vested transfer works
+		System::set_block_number(1);
+
+		let schedule = stepped(2, 10, 2, 10);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, schedule.clone()));
+
+		assert_eq!(Vesting::vesting_schedules(BOB), vec![from_alice(schedule.clone())]);
+		assert_eq!(PalletBalances::free_balance(ALICE), ALICE_BALANCE - 20);
+		assert_eq!(PalletBalances::free_balance(BOB), 20);
+		assert_eq!(frozen(BOB), 20);
+		System::assert_last_event(
+			Event::VestingScheduleAdded { from: ALICE, to: BOB, schedule: from_alice(schedule) }.into(),
+		);
 	});
 }
 
 
 #[test]
 fn vested_transfer_fails_if_zero_period_or_count() {
-    ExtBuilder::build().execute_with(|| { 
-		// TODO: check if the vested transfer fails if the period or count is zero
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+
+		let schedule = stepped(2, 0, 2, 10);
+		assert_noop!(
+			Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, schedule),
+			Error::<Runtime>::ZeroVestingPeriod
+		);
+
+		let schedule = stepped(2, 10, 0, 10);
+		assert_noop!(
+			Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, schedule),
+			Error::<Runtime>::ZeroVestingPeriodCount
+		);
+	});
+}
+
+#[test]
+fn vested_transfer_fails_if_too_many_schedules() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
 
-    });
+		let schedule = stepped(2, 10, 1, 10);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, schedule.clone()));
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, schedule.clone()));
+		assert_noop!(
+			Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, schedule),
+			Error::<Runtime>::TooManyVestingSchedules
+		);
+	});
 }
 
 #[test]
 fn claim_works() {
 	ExtBuilder::build().execute_with(|| {
-		// TODO: check if the claim works
+		System::set_block_number(1);
+
+		let schedule = stepped(2, 10, 2, 10);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, schedule));
+
+		System::set_block_number(12);
+		assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB)));
+		assert_eq!(frozen(BOB), 10);
+		System::assert_last_event(Event::Claimed { who: BOB, unlocked: 10, locked: 10 }.into());
+
+		System::set_block_number(22);
+		assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB)));
+		assert_eq!(frozen(BOB), 0);
+		System::assert_has_event(Event::Claimed { who: BOB, unlocked: 10, locked: 0 }.into());
+		System::assert_last_event(Event::VestingCompleted { who: BOB }.into());
 	});
 }
 
 #[test]
 fn update_vesting_schedules_works() {
 	ExtBuilder::build().execute_with(|| {
-		// TODO: check if the update vesting schedules works
+		System::set_block_number(1);
+
+		let schedule = stepped(1, 10, 5, 10);
+		assert_noop!(
+			Vesting::update_vesting_schedules(RuntimeOrigin::signed(ALICE), ALICE, vec![schedule.clone()]),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(Vesting::update_vesting_schedules(RuntimeOrigin::root(), ALICE, vec![schedule.clone()]));
+		assert_eq!(Vesting::vesting_schedules(ALICE), vec![schedule.clone()]);
+		assert_eq!(frozen(ALICE), 50);
+		System::assert_last_event(
+			Event::VestingSchedulesUpdated {
+				who: ALICE,
+				old_schedules: Default::default(),
+				new_schedules: vec![schedule].try_into().unwrap(),
+			}
+			.into(),
+		);
+
+		assert_ok!(Vesting::update_vesting_schedules(RuntimeOrigin::root(), ALICE, vec![]));
+		assert!(Vesting::vesting_schedules(ALICE).is_empty());
+		assert_eq!(frozen(ALICE), 0);
 	});
 }
 
+#[test]
+fn update_vesting_schedule_works() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+
+		let old_schedule = stepped(2, 10, 2, 10);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, old_schedule.clone()));
+
+		let new_schedule = stepped(2, 5, 4, 5);
+		assert_noop!(
+			Vesting::update_vesting_schedule(RuntimeOrigin::root(), BOB, 1, new_schedule.clone()),
+			Error::<Runtime>::InvalidVestingIndex
+		);
+		assert_ok!(Vesting::update_vesting_schedule(RuntimeOrigin::root(), BOB, 0, new_schedule.clone()));
+
+		assert_eq!(Vesting::vesting_schedules(BOB), vec![new_schedule.clone()]);
+		System::assert_last_event(
+			Event::VestingScheduleUpdated {
+				who: BOB,
+				index: 0,
+				old_schedule: from_alice(old_schedule),
+				new_schedule,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn force_remove_vesting_schedule_works() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+
+		let schedule = stepped(2, 10, 2, 10);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, schedule.clone()));
+
+		assert_noop!(
+			Vesting::force_remove_vesting_schedule(RuntimeOrigin::root(), BOB, 1),
+			Error::<Runtime>::InvalidVestingIndex
+		);
+		assert_ok!(Vesting::force_remove_vesting_schedule(RuntimeOrigin::root(), BOB, 0));
+
+		assert!(Vesting::vesting_schedules(BOB).is_empty());
+		assert_eq!(frozen(BOB), 0);
+		System::assert_last_event(
+			Event::VestingScheduleRemoved { who: BOB, index: 0, schedule: from_alice(schedule) }.into(),
+		);
+	});
+}
+
+#[test]
+fn admin_calls_require_admin_origin() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, stepped(2, 10, 2, 10)));
+		let schedule = stepped(2, 5, 4, 5);
+
+		assert_noop!(
+			Vesting::update_vesting_schedules(RuntimeOrigin::signed(ALICE), BOB, vec![schedule.clone()]),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Vesting::update_vesting_schedule(RuntimeOrigin::signed(ALICE), BOB, 0, schedule.clone()),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Vesting::force_remove_vesting_schedule(RuntimeOrigin::signed(ALICE), BOB, 0),
+			DispatchError::BadOrigin
+		);
+
+		// CHARLIE is the mock's `AdminOrigin` besides Root.
+		assert_ok!(Vesting::update_vesting_schedules(RuntimeOrigin::signed(CHARLIE), BOB, vec![schedule.clone()]));
+		assert_ok!(Vesting::update_vesting_schedule(RuntimeOrigin::signed(CHARLIE), BOB, 0, schedule.clone()));
+		assert_ok!(Vesting::force_remove_vesting_schedule(RuntimeOrigin::signed(CHARLIE), BOB, 0));
+		assert!(Vesting::vesting_schedules(BOB).is_empty());
+	});
+}
+
+
 
 #[test]
 fn multiple_vesting_schedule_claim_works() {
 	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+
+		let schedule = stepped(2, 10, 2, 10);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, schedule));
+
+		let schedule = stepped(10, 5, 3, 5);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, schedule));
+		assert_eq!(frozen(BOB), 35);
 
-		// TODO: check if the multiple vesting schedule claim works
+		System::set_block_number(15);
+		assert_eq!(Vesting::vested_balance(&BOB), 15);
+		assert_eq!(Vesting::locked_balance(&BOB), 20);
+		assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB)));
+		assert_eq!(frozen(BOB), 20);
+
+		System::set_block_number(25);
+		assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB)));
+		assert_eq!(frozen(BOB), 0);
+		System::assert_last_event(Event::VestingCompleted { who: BOB }.into());
 	});
 }
 
+#[test]
+fn migrate_locks_to_freezes_works() {
+	use migrations::v1::LazyMigrateLocksToFreezes;
+	use polkadot_sdk::frame_support::{migrations::SteppedMigration, weights::WeightMeter};
+
+	ExtBuilder::build().execute_with(|| {
+		StorageVersion::new(0).put::<Vesting>();
+
+		let schedule = stepped(2, 10, 2, 10);
+		VestingSchedules::<Runtime>::insert(ALICE, BoundedVec::truncate_from(vec![schedule]));
+		<PalletBalances as LockableCurrency<AccountId>>::set_lock(
+			VESTING_ID,
+			&ALICE,
+			20,
+			WithdrawReasons::all(),
+		);
+
+		let step = LazyMigrateLocksToFreezes::<Runtime, PalletBalances>::step_weight();
+		let mut cursor = None;
+		loop {
+			cursor = LazyMigrateLocksToFreezes::<Runtime, PalletBalances>::step(
+				cursor,
+				&mut WeightMeter::with_limit(step),
+			)
+			.unwrap();
+			if cursor.is_none() {
+				break;
+			}
+		}
+
+		assert!(PalletBalances::locks(&ALICE).is_empty());
+		assert_eq!(frozen(ALICE), 20);
+		assert_eq!(Vesting::on_chain_storage_version(), StorageVersion::new(1));
+	});
+}
+
+#[test]
+fn lazy_migration_v1_to_v2_works() {
+	use migrations::v2::{old, LazyMigrationV1ToV2, OldVestingSchedule};
+	use polkadot_sdk::frame_support::{
+		migrations::{SteppedMigration, SteppedMigrationError},
+		weights::WeightMeter,
+	};
+
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(30);
+		StorageVersion::new(1).put::<Vesting>();
+
+		let old_schedule = OldVestingSchedule { start: 2, period: 10, period_count: 2, per_period: 10 };
+		old::VestingSchedules::<Runtime>::insert(ALICE, BoundedVec::truncate_from(vec![old_schedule.clone()]));
+		old::VestingSchedules::<Runtime>::insert(
+			CHARLIE,
+			BoundedVec::truncate_from(vec![old_schedule.clone(), old_schedule]),
+		);
+
+		let step = <() as WeightInfo>::migrate_v1_to_v2_step(2);
+		assert!(matches!(
+			LazyMigrationV1ToV2::<Runtime>::step(None, &mut WeightMeter::with_limit(Weight::zero())),
+			Err(SteppedMigrationError::InsufficientWeight { required }) if required == step
+		));
+
+		// One account per step, and a last step to notice there is nothing left.
+		let mut cursor = None;
+		let mut steps = 0;
+		loop {
+			cursor = LazyMigrationV1ToV2::<Runtime>::step(cursor, &mut WeightMeter::with_limit(step))
+				.unwrap();
+			steps += 1;
+			if cursor.is_none() {
+				break;
+			}
+		}
+		assert_eq!(steps, 3);
+
+		assert_eq!(Vesting::vesting_schedules(ALICE), vec![stepped(2, 10, 2, 10)]);
+		assert_eq!(Vesting::vesting_schedules(CHARLIE), vec![stepped(2, 10, 2, 10), stepped(2, 10, 2, 10)]);
+		assert_eq!(Vesting::on_chain_storage_version(), StorageVersion::new(2));
+		// The schedules ended before the upgrade, so they are due at the next cleanup.
+		assert_eq!(ScheduleEnds::<Runtime>::get(schedule_end_key(22u64), ALICE), Some(()));
+	});
+}
+
+#[cfg(feature = "try-runtime")]
+#[test]
+fn lazy_migration_v1_to_v2_passes_try_runtime_checks() {
+	use migrations::v2::{old, LazyMigrationV1ToV2, OldVestingSchedule};
+	use polkadot_sdk::frame_support::{migrations::SteppedMigration, weights::WeightMeter};
+
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(10);
+		StorageVersion::new(1).put::<Vesting>();
+
+		let ended = OldVestingSchedule { start: 2, period: 2, period_count: 2, per_period: 10 };
+		let running = OldVestingSchedule { start: 5, period: 10, period_count: 3, per_period: 10 };
+		old::VestingSchedules::<Runtime>::insert(ALICE, BoundedVec::truncate_from(vec![ended, running.clone()]));
+		old::VestingSchedules::<Runtime>::insert(BOB, BoundedVec::truncate_from(vec![running]));
+
+		let state = LazyMigrationV1ToV2::<Runtime>::pre_upgrade().unwrap();
+		let step = <() as WeightInfo>::migrate_v1_to_v2_step(2);
+		let mut cursor = None;
+		loop {
+			cursor = LazyMigrationV1ToV2::<Runtime>::step(cursor, &mut WeightMeter::with_limit(step))
+				.unwrap();
+			if cursor.is_none() {
+				break;
+			}
+		}
+		assert_ok!(LazyMigrationV1ToV2::<Runtime>::post_upgrade(state));
+
+		assert_eq!(Vesting::vesting_schedules(BOB), vec![stepped(5, 10, 3, 10)]);
+		assert_eq!(ScheduleEnds::<Runtime>::get(schedule_end_key(35u64), BOB), Some(()));
+	});
+}
+
+#[test]
+fn stepped_curve_works() {
+	let schedule = stepped(100, 10, 5, 1000);
+	assert_eq!(schedule.total_amount(), Some(5000));
+	assert_eq!(schedule.vested_amount::<ConvertInto>(99), 0);
+	assert_eq!(schedule.vested_amount::<ConvertInto>(109), 0);
+	assert_eq!(schedule.vested_amount::<ConvertInto>(120), 2000);
+	assert_eq!(schedule.vested_amount::<ConvertInto>(1000), 5000);
+	assert_eq!(schedule.locked_amount::<ConvertInto>(120), 3000);
+
+	let overflowing = stepped(100, 10, 2, u64::MAX);
+	assert_eq!(overflowing.total_amount(), None);
+	assert_eq!(overflowing.vested_amount::<ConvertInto>(200), u64::MAX);
+}
+
+#[test]
+fn cliff_curve_works() {
+	let schedule = with_curve(100, VestingCurve::Cliff { cliff: 25, duration: 100, total: 1000 });
+	assert_eq!(schedule.total_amount(), Some(1000));
+	assert_eq!(schedule.vested_amount::<ConvertInto>(124), 0);
+	assert_eq!(schedule.vested_amount::<ConvertInto>(125), 250);
+	assert_eq!(schedule.vested_amount::<ConvertInto>(150), 500);
+	assert_eq!(schedule.vested_amount::<ConvertInto>(200), 1000);
+	assert_eq!(schedule.locked_amount::<ConvertInto>(150), 500);
+}
+
+#[test]
+fn linear_curve_works() {
+	let schedule = with_curve(100, VestingCurve::Linear { duration: 3, total: 10 });
+	assert_eq!(schedule.vested_amount::<ConvertInto>(100), 0);
+	assert_eq!(schedule.vested_amount::<ConvertInto>(101), 3);
+	assert_eq!(schedule.vested_amount::<ConvertInto>(102), 6);
+	assert_eq!(schedule.vested_amount::<ConvertInto>(103), 10);
+
+	// `total × elapsed` would overflow a u64 without the 128-bit intermediate.
+	let schedule = with_curve(0, VestingCurve::Linear { duration: 4, total: u64::MAX });
+	assert_eq!(schedule.vested_amount::<ConvertInto>(2), u64::MAX / 2);
+	assert_eq!(schedule.locked_amount::<ConvertInto>(4), 0);
+}
+
+#[test]
+fn tranches_curve_works() {
+	let schedule = with_curve(100, VestingCurve::Tranches(BoundedVec::truncate_from(vec![(0, 10), (50, 20), (80, 30)])));
+	assert_eq!(schedule.total_amount(), Some(60));
+	assert_eq!(schedule.vested_amount::<ConvertInto>(99), 0);
+	assert_eq!(schedule.vested_amount::<ConvertInto>(100), 10);
+	assert_eq!(schedule.vested_amount::<ConvertInto>(149), 10);
+	assert_eq!(schedule.vested_amount::<ConvertInto>(150), 30);
+	assert_eq!(schedule.vested_amount::<ConvertInto>(180), 60);
+	assert_eq!(schedule.locked_amount::<ConvertInto>(150), 30);
+
+	let overflowing = with_curve(100, VestingCurve::Tranches(BoundedVec::truncate_from(vec![(0, u64::MAX), (1, 1)])));
+	assert_eq!(overflowing.total_amount(), None);
+}
+
+#[test]
+fn speed_up_and_vest_now_work() {
+	// Two periods have passed, the remaining three now take 5 blocks each.
+	let mut schedule = stepped(100, 10, 5, 1000);
+	schedule.speed_up(2, 125, None);
+	assert_eq!(schedule, stepped(112, 5, 5, 1000));
+	assert_eq!(schedule.vested_amount::<ConvertInto>(125), 2000);
+	assert_eq!(schedule.vested_amount::<ConvertInto>(127), 3000);
+
+	let mut schedule = with_curve(100, VestingCurve::Tranches(BoundedVec::truncate_from(vec![(0, 10), (50, 20), (80, 30)])));
+	schedule.speed_up(50, 100, None);
+	assert_eq!(schedule.curve, VestingCurve::Tranches(BoundedVec::truncate_from(vec![(0, 10), (1, 50)])));
+	assert_eq!(schedule.total_amount(), Some(60));
+
+	let mut schedule = with_curve(100, VestingCurve::Linear { duration: 100, total: 100 });
+	schedule.vest_now(Percent::from_percent(50));
+	assert_eq!(schedule.released_early, 50);
+	assert_eq!(schedule.total_amount(), Some(100));
+	assert_eq!(schedule.vested_amount::<ConvertInto>(99), 50);
+	assert_eq!(schedule.vested_amount::<ConvertInto>(150), 75);
+	assert_eq!(schedule.vested_amount::<ConvertInto>(200), 100);
+
+	schedule.vest_now(Percent::from_percent(100));
+	assert_eq!(schedule.locked_amount::<ConvertInto>(99), 0);
+	assert_eq!(schedule.next_unlock(99), None);
+	assert_eq!(schedule.end(), Some(100));
+}
+
+#[test]
+fn vested_transfer_validates_curves() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+
+		let cliff_too_long = with_curve(2, VestingCurve::Cliff { cliff: 11, duration: 10, total: 20 });
+		assert_noop!(
+			Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, cliff_too_long),
+			Error::<Runtime>::InvalidCliff
+		);
+
+		let no_duration = with_curve(2, VestingCurve::Linear { duration: 0, total: 20 });
+		assert_noop!(
+			Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, no_duration),
+			Error::<Runtime>::ZeroVestingPeriod
+		);
+
+		let unsorted = with_curve(2, VestingCurve::Tranches(BoundedVec::truncate_from(vec![(10, 10), (10, 10)])));
+		assert_noop!(
+			Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, unsorted),
+			Error::<Runtime>::UnsortedTranches
+		);
+
+		let linear = with_curve(2, VestingCurve::Linear { duration: 10, total: 20 });
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, linear));
+		assert_eq!(frozen(BOB), 20);
+
+		System::set_block_number(7);
+		assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB)));
+		assert_eq!(frozen(BOB), 10);
+	});
+}
+
+#[test]
+fn merge_schedules_works() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+
+		let schedule_a = with_curve(2, VestingCurve::Linear { duration: 20, total: 20 });
+		let schedule_b = with_curve(10, VestingCurve::Linear { duration: 10, total: 15 });
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, schedule_a.clone()));
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, schedule_b.clone()));
+
+		System::set_block_number(12);
+		assert_noop!(
+			Vesting::merge_schedules(RuntimeOrigin::signed(BOB), 0, 0),
+			Error::<Runtime>::ScheduleIndexesEqual
+		);
+		assert_noop!(
+			Vesting::merge_schedules(RuntimeOrigin::signed(BOB), 0, 2),
+			Error::<Runtime>::InvalidVestingIndex
+		);
+
+		let locked_before = Vesting::locked_balance(&BOB);
+		assert_ok!(Vesting::merge_schedules(RuntimeOrigin::signed(BOB), 1, 0));
+
+		// 10 still locked in the first schedule and 12 in the second, released until block 22.
+		let merged = from_alice(with_curve(12, VestingCurve::Linear { duration: 10, total: 22 }));
+		assert_eq!(Vesting::vesting_schedules(BOB), vec![merged.clone()]);
+		assert_eq!(Vesting::locked_balance(&BOB), locked_before);
+		assert_eq!(frozen(BOB), 22);
+		System::assert_last_event(
+			Event::VestingSchedulesMerged {
+				who: BOB,
+				schedule_a: from_alice(schedule_b),
+				schedule_b: from_alice(schedule_a),
+				merged: Some(merged),
+			}
+			.into(),
+		);
+
+		// The originals would still lock 5 each.
+		System::set_block_number(17);
+		assert_eq!(Vesting::locked_balance(&BOB), 11);
+
+		System::set_block_number(22);
+		assert_eq!(Vesting::locked_balance(&BOB), 0);
+	});
+}
+
+#[test]
+fn merge_fully_vested_schedules_removes_them() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, stepped(2, 10, 1, 10)));
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, stepped(2, 10, 1, 10)));
+
+		System::set_block_number(20);
+		assert_ok!(Vesting::merge_schedules(RuntimeOrigin::signed(BOB), 0, 1));
+		assert!(Vesting::vesting_schedules(BOB).is_empty());
+		assert_eq!(frozen(BOB), 0);
+	});
+}
+
+#[test]
+fn split_schedule_works() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, stepped(2, 10, 2, 10)));
+
+		assert_noop!(
+			Vesting::split_schedule(RuntimeOrigin::signed(BOB), 0, 3),
+			Error::<Runtime>::InvalidSplitAmount
+		);
+		assert_noop!(
+			Vesting::split_schedule(RuntimeOrigin::signed(BOB), 0, 20),
+			Error::<Runtime>::InvalidSplitAmount
+		);
+		assert_noop!(
+			Vesting::split_schedule(RuntimeOrigin::signed(BOB), 1, 10),
+			Error::<Runtime>::InvalidVestingIndex
+		);
+
+		assert_ok!(Vesting::split_schedule(RuntimeOrigin::signed(BOB), 0, 10));
+		let half = from_alice(stepped(2, 10, 2, 5));
+		assert_eq!(Vesting::vesting_schedules(BOB), vec![half.clone(), half.clone()]);
+		assert_eq!(frozen(BOB), 20);
+		System::assert_last_event(
+			Event::VestingScheduleSplit {
+				who: BOB,
+				index: 0,
+				remaining: half.clone(),
+				split: half,
+			}
+			.into(),
+		);
+
+		assert_noop!(
+			Vesting::split_schedule(RuntimeOrigin::signed(BOB), 0, 4),
+			Error::<Runtime>::TooManyVestingSchedules
+		);
+	});
+}
+
+#[test]
+fn split_off_tranches_takes_latest_first() {
+	let mut schedule =
+		with_curve(100, VestingCurve::Tranches(BoundedVec::truncate_from(vec![(0, 10), (50, 20), (80, 30)])));
+	let split = schedule.split_off(40).unwrap();
+
+	assert_eq!(
+		schedule.curve,
+		VestingCurve::Tranches(BoundedVec::truncate_from(vec![(0, 10), (50, 10)]))
+	);
+	assert_eq!(split.curve, VestingCurve::Tranches(BoundedVec::truncate_from(vec![(50, 10), (80, 30)])));
+	for (now, vested) in [(100, 10), (149, 10), (150, 30), (180, 60)] {
+		assert_eq!(
+			schedule.vested_amount::<ConvertInto>(now) + split.vested_amount::<ConvertInto>(now),
+			vested
+		);
+	}
+}
+
+fn revocable(schedule: VestingScheduleOf<Runtime>) -> VestingScheduleOf<Runtime> {
+	VestingSchedule { revocable: true, ..schedule }
+}
+
+#[test]
+fn revoke_works() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, revocable(stepped(2, 10, 2, 10))));
+
+		System::set_block_number(12);
+		assert_noop!(Vesting::revoke(RuntimeOrigin::signed(CHARLIE), BOB, 0), Error::<Runtime>::NotGrantor);
+		assert_noop!(Vesting::revoke(RuntimeOrigin::signed(ALICE), BOB, 1), Error::<Runtime>::InvalidVestingIndex);
+		assert_ok!(Vesting::freeze_vesting(RuntimeOrigin::root(), BOB));
+		assert_noop!(Vesting::revoke(RuntimeOrigin::signed(ALICE), BOB, 0), Error::<Runtime>::AccountFrozen);
+		assert_ok!(Vesting::unfreeze_vesting(RuntimeOrigin::root(), BOB));
+
+		assert_ok!(Vesting::revoke(RuntimeOrigin::signed(ALICE), BOB, 0));
+		assert_eq!(Vesting::vesting_schedules(BOB), vec![]);
+		assert_eq!(frozen(BOB), 0);
+		assert_eq!(PalletBalances::free_balance(BOB), 10);
+		assert_eq!(PalletBalances::free_balance(ALICE), ALICE_BALANCE - 10);
+		System::assert_last_event(
+			Event::VestingRevoked { who: BOB, index: 0, grantor: ALICE, beneficiary: ALICE, amount: 10 }.into(),
+		);
+	});
+}
+
+#[test]
+fn revoke_fails_if_not_revocable() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, stepped(2, 10, 2, 10)));
+
+		assert_noop!(Vesting::revoke(RuntimeOrigin::signed(ALICE), BOB, 0), Error::<Runtime>::NotRevocable);
+	});
+}
+
+#[test]
+fn revoke_pays_revoke_beneficiary() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, revocable(stepped(2, 10, 2, 10))));
+		assert_ok!(Vesting::set_revoke_beneficiary(RuntimeOrigin::signed(ALICE), Some(CHARLIE)));
+		System::assert_last_event(Event::RevokeBeneficiarySet { grantor: ALICE, beneficiary: Some(CHARLIE) }.into());
+
+		assert_ok!(Vesting::revoke(RuntimeOrigin::signed(ALICE), BOB, 0));
+		assert_eq!(PalletBalances::free_balance(BOB), 0);
+		assert_eq!(PalletBalances::free_balance(CHARLIE), CHARLIE_BALANCE + 20);
+		System::assert_last_event(
+			Event::VestingRevoked { who: BOB, index: 0, grantor: ALICE, beneficiary: CHARLIE, amount: 20 }.into(),
+		);
+
+		assert_ok!(Vesting::set_revoke_beneficiary(RuntimeOrigin::signed(ALICE), None));
+		assert_eq!(Vesting::revoke_beneficiary(ALICE), None);
+	});
+}
+
+#[test]
+fn merge_schedules_fails_if_incompatible() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, stepped(2, 10, 2, 10)));
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, revocable(stepped(2, 10, 2, 10))));
+
+		assert_noop!(
+			Vesting::merge_schedules(RuntimeOrigin::signed(BOB), 0, 1),
+			Error::<Runtime>::IncompatibleSchedules
+		);
+
+		// A linear release would unlock a stepped schedule between its steps.
+		let linear = with_curve(2, VestingCurve::Linear { duration: 20, total: 20 });
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), CHARLIE, linear));
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), CHARLIE, stepped(2, 10, 2, 10)));
+		assert_noop!(
+			Vesting::merge_schedules(RuntimeOrigin::signed(CHARLIE), 0, 1),
+			Error::<Runtime>::IncompatibleSchedules
+		);
+	});
+}
+
+#[test]
+fn merge_schedules_cannot_skip_a_cliff() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		let cliff = with_curve(2, VestingCurve::Cliff { cliff: 100, duration: 100, total: 40 });
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, cliff));
+		assert_ok!(Vesting::split_schedule(RuntimeOrigin::signed(BOB), 0, 20));
+
+		System::set_block_number(52);
+		assert_noop!(
+			Vesting::merge_schedules(RuntimeOrigin::signed(BOB), 0, 1),
+			Error::<Runtime>::IncompatibleSchedules
+		);
+		assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB)));
+		assert_eq!(Vesting::locked_balance(&BOB), 40);
+		assert_eq!(frozen(BOB), 40);
+	});
+}
+
+#[test]
+fn transfer_schedule_works() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		let schedule = from_alice(stepped(2, 10, 2, 10));
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, schedule.clone()));
+
+		System::set_block_number(12);
+		assert_noop!(
+			Vesting::transfer_schedule(RuntimeOrigin::signed(BOB), 1, CHARLIE),
+			Error::<Runtime>::InvalidVestingIndex
+		);
+
+		assert_ok!(Vesting::transfer_schedule(RuntimeOrigin::signed(BOB), 0, CHARLIE));
+		assert_eq!(Vesting::vesting_schedules(BOB), vec![]);
+		assert_eq!(Vesting::vesting_schedules(CHARLIE), vec![schedule.clone()]);
+		assert_eq!(PalletBalances::free_balance(BOB), 10);
+		assert_eq!(frozen(BOB), 0);
+		assert_eq!(PalletBalances::free_balance(CHARLIE), CHARLIE_BALANCE + 10);
+		assert_eq!(frozen(CHARLIE), 10);
+		assert_eq!(ScheduleEnds::<Runtime>::get(schedule_end_key(22u64), BOB), None);
+		assert_eq!(ScheduleEnds::<Runtime>::get(schedule_end_key(22u64), CHARLIE), Some(()));
+		System::assert_last_event(
+			Event::VestingScheduleTransferred { from: BOB, to: CHARLIE, index: 0, schedule, amount: 10 }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn transfer_schedule_keeps_shared_schedule_ends() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, stepped(2, 10, 2, 10)));
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, stepped(12, 10, 1, 10)));
+
+		// BOB's other schedule ends at the same block, so BOB stays indexed.
+		assert_ok!(Vesting::transfer_schedule(RuntimeOrigin::signed(BOB), 0, CHARLIE));
+		assert_eq!(ScheduleEnds::<Runtime>::get(schedule_end_key(22u64), BOB), Some(()));
+		assert_eq!(ScheduleEnds::<Runtime>::get(schedule_end_key(22u64), CHARLIE), Some(()));
+	});
+}
+
+#[test]
+fn transfer_schedule_fails_if_receiver_is_frozen() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, stepped(2, 10, 2, 10)));
+		assert_ok!(Vesting::freeze_vesting(RuntimeOrigin::root(), CHARLIE));
+
+		System::set_block_number(12);
+		assert_noop!(
+			Vesting::transfer_schedule(RuntimeOrigin::signed(BOB), 0, CHARLIE),
+			Error::<Runtime>::AccountFrozen
+		);
+	});
+}
+
+#[test]
+fn transfer_schedule_fails_if_receiver_is_full() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, stepped(2, 10, 2, 10)));
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), CHARLIE, stepped(2, 10, 2, 10)));
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), CHARLIE, stepped(2, 10, 2, 10)));
+
+		assert_noop!(
+			Vesting::transfer_schedule(RuntimeOrigin::signed(BOB), 0, CHARLIE),
+			Error::<Runtime>::TooManyVestingSchedules
+		);
+	});
+}
+
+fn milestones(milestone_count: u32, total: u64) -> VestingScheduleOf<Runtime> {
+	with_curve(2, VestingCurve::Milestones { milestone_count, total, released: BoundedVec::new() })
+}
+
+#[test]
+fn release_milestone_works() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, milestones(3, 30)));
 
+		// Time alone does not release anything.
+		System::set_block_number(100);
+		assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB)));
+		assert_eq!(frozen(BOB), 30);
+		assert_eq!(Vesting::next_unlock(&BOB), None);
 
+		assert_ok!(Vesting::release_milestone(RuntimeOrigin::root(), BOB, 0, 2));
+		assert_eq!(frozen(BOB), 20);
+		System::assert_last_event(
+			Event::MilestoneReleased { who: BOB, index: 0, milestone_id: 2, locked: 20 }.into(),
+		);
+
+		assert_ok!(Vesting::release_milestone(RuntimeOrigin::root(), BOB, 0, 0));
+		assert_ok!(Vesting::release_milestone(RuntimeOrigin::root(), BOB, 0, 1));
+		assert_eq!(frozen(BOB), 0);
+
+		// The fully released schedule is pruned by `on_idle`.
+		Vesting::on_idle(100, Weight::MAX);
+		assert!(Vesting::vesting_schedules(BOB).is_empty());
+	});
+}
+
+#[test]
+fn release_milestone_fails_if_invalid() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, milestones(2, 20)));
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, stepped(2, 10, 2, 10)));
+
+		assert_noop!(
+			Vesting::release_milestone(RuntimeOrigin::signed(ALICE), BOB, 0, 0),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Vesting::release_milestone(RuntimeOrigin::root(), BOB, 2, 0),
+			Error::<Runtime>::InvalidVestingIndex
+		);
+		assert_noop!(
+			Vesting::release_milestone(RuntimeOrigin::root(), BOB, 1, 0),
+			Error::<Runtime>::NotMilestoneSchedule
+		);
+		assert_noop!(
+			Vesting::release_milestone(RuntimeOrigin::root(), BOB, 0, 2),
+			Error::<Runtime>::InvalidMilestone
+		);
+
+		assert_ok!(Vesting::freeze_vesting(RuntimeOrigin::root(), BOB));
+		assert_noop!(
+			Vesting::release_milestone(RuntimeOrigin::root(), BOB, 0, 1),
+			Error::<Runtime>::AccountFrozen
+		);
+		assert_ok!(Vesting::unfreeze_vesting(RuntimeOrigin::root(), BOB));
+
+		assert_ok!(Vesting::release_milestone(RuntimeOrigin::root(), BOB, 0, 1));
+		assert_noop!(
+			Vesting::release_milestone(RuntimeOrigin::root(), BOB, 0, 1),
+			Error::<Runtime>::MilestoneAlreadyReleased
+		);
+		assert_noop!(
+			Vesting::merge_schedules(RuntimeOrigin::signed(BOB), 0, 1),
+			Error::<Runtime>::IncompatibleSchedules
+		);
+	});
+}
+
+#[test]
+fn batch_vested_transfer_works() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+
+		let grants = BoundedVec::truncate_from(vec![
+			(BOB, stepped(2, 10, 2, 10)),
+			(CHARLIE, stepped(2, 10, 3, 10)),
+			(BOB, stepped(5, 1, 1, 5)),
+		]);
+		assert_ok!(Vesting::batch_vested_transfer(RuntimeOrigin::signed(ALICE), grants));
+
+		assert_eq!(
+			Vesting::vesting_schedules(BOB),
+			vec![from_alice(stepped(2, 10, 2, 10)), from_alice(stepped(5, 1, 1, 5))]
+		);
+		assert_eq!(Vesting::vesting_schedules(CHARLIE), vec![from_alice(stepped(2, 10, 3, 10))]);
+		assert_eq!(PalletBalances::free_balance(ALICE), ALICE_BALANCE - 55);
+		assert_eq!(frozen(BOB), 25);
+		assert_eq!(frozen(CHARLIE), 30);
+	});
+}
+
+#[test]
+fn batch_vested_transfer_is_atomic() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), CHARLIE, stepped(2, 10, 2, 10)));
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), CHARLIE, stepped(2, 10, 2, 10)));
+
+		let grants = BoundedVec::truncate_from(vec![
+			(BOB, stepped(2, 10, 2, 10)),
+			(CHARLIE, stepped(2, 10, 2, 10)),
+		]);
+		assert_noop!(
+			Vesting::batch_vested_transfer(RuntimeOrigin::signed(ALICE), grants),
+			Error::<Runtime>::BatchGrantFailed {
+				index: 1,
+				reason: BatchGrantError::TooManyVestingSchedules,
+			}
+		);
+
+		let grants = BoundedVec::truncate_from(vec![
+			(BOB, stepped(2, 10, 2, 10)),
+			(BOB, stepped(2, 1, 1, 1)),
+		]);
+		assert_noop!(
+			Vesting::batch_vested_transfer(RuntimeOrigin::signed(ALICE), grants),
+			Error::<Runtime>::BatchGrantFailed { index: 1, reason: BatchGrantError::AmountLow }
+		);
+
+		// ALICE has 60 left, less than the batch total.
+		let grants = BoundedVec::truncate_from(vec![
+			(BOB, stepped(2, 10, 2, 30)),
+			(BOB, stepped(2, 10, 1, 10)),
+		]);
+		assert_noop!(
+			Vesting::batch_vested_transfer(RuntimeOrigin::signed(ALICE), grants),
+			Error::<Runtime>::InsufficientBalanceToLock
+		);
+	});
+}
+
+#[test]
+fn accepted_grantors_protect_recipients() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		let grantors = BoundedVec::truncate_from(vec![CHARLIE]);
+		assert_ok!(Vesting::set_accepted_grantors(RuntimeOrigin::signed(BOB), Some(grantors.clone())));
+		System::assert_last_event(Event::AcceptedGrantorsSet { who: BOB, grantors: Some(grantors) }.into());
+
+		assert_noop!(
+			Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, stepped(2, 10, 2, 10)),
+			Error::<Runtime>::GrantorNotAccepted
+		);
+		assert_noop!(
+			Vesting::batch_vested_transfer(
+				RuntimeOrigin::signed(ALICE),
+				BoundedVec::truncate_from(vec![(BOB, stepped(2, 10, 2, 10))])
+			),
+			Error::<Runtime>::BatchGrantFailed { index: 0, reason: BatchGrantError::GrantorNotAccepted }
+		);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(CHARLIE), BOB, stepped(2, 10, 2, 10)));
+
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), CHARLIE, stepped(2, 10, 2, 10)));
+		assert_ok!(Vesting::set_accepted_grantors(
+			RuntimeOrigin::signed(BOB),
+			Some(BoundedVec::truncate_from(vec![ALICE]))
+		));
+		assert_noop!(
+			Vesting::transfer_schedule(RuntimeOrigin::signed(CHARLIE), 0, BOB),
+			Error::<Runtime>::GrantorNotAccepted
+		);
+
+		assert_ok!(Vesting::set_accepted_grantors(RuntimeOrigin::signed(BOB), None));
+		assert_ok!(Vesting::transfer_schedule(RuntimeOrigin::signed(CHARLIE), 0, BOB));
+		assert_eq!(Vesting::vesting_schedules(BOB).len(), 2);
+	});
+}
+
+fn deposit_held(who: AccountId) -> u64 {
+	PalletBalances::balance_on_hold(&HoldReason::ScheduleDeposit.into(), &who)
+}
+
+#[test]
+fn schedule_deposit_is_held_and_released() {
+	ExtBuilder::build().execute_with(|| {
+		ScheduleDeposit::set(5);
+		System::set_block_number(1);
+
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, stepped(2, 10, 2, 10)));
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, stepped(2, 10, 2, 10)));
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), CHARLIE, stepped(2, 10, 2, 10)));
+		assert_eq!(PalletBalances::free_balance(ALICE), ALICE_BALANCE - 60 - 15);
+		assert_eq!(deposit_held(ALICE), 15);
+		assert_eq!(Vesting::vesting_schedules(BOB)[0].deposit, 5);
+
+		assert_ok!(Vesting::force_remove_vesting_schedule(RuntimeOrigin::root(), BOB, 0));
+		assert_eq!(deposit_held(ALICE), 10);
+
+		assert_ok!(Vesting::update_vesting_schedules(RuntimeOrigin::root(), CHARLIE, vec![]));
+		assert_eq!(deposit_held(ALICE), 5);
+
+		// The last schedule completes and is pruned.
+		System::set_block_number(22);
+		Vesting::on_idle(22, Weight::MAX);
+		assert_eq!(deposit_held(ALICE), 0);
+		assert_eq!(PalletBalances::free_balance(ALICE), ALICE_BALANCE - 60);
+	});
+}
+
+#[test]
+fn split_schedule_shares_the_deposit() {
+	ExtBuilder::build().execute_with(|| {
+		ScheduleDeposit::set(5);
+		System::set_block_number(1);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, stepped(2, 10, 2, 10)));
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), CHARLIE, stepped(2, 10, 2, 10)));
+
+		assert_ok!(Vesting::split_schedule(RuntimeOrigin::signed(BOB), 0, 10));
+		assert_eq!(Vesting::vesting_schedules(BOB)[0].deposit, 3);
+		assert_eq!(Vesting::vesting_schedules(BOB)[1].deposit, 2);
+		assert_eq!(deposit_held(ALICE), 10);
+
+		// Each half returns its share of the one deposit, CHARLIE's grant keeps its own.
+		assert_ok!(Vesting::force_remove_vesting_schedule(RuntimeOrigin::root(), BOB, 1));
+		assert_eq!(deposit_held(ALICE), 8);
+		assert_ok!(Vesting::force_remove_vesting_schedule(RuntimeOrigin::root(), BOB, 0));
+		assert_eq!(deposit_held(ALICE), 5);
+	});
+}
+
+#[test]
+fn claim_prunes_completed_schedules_and_releases_deposits() {
+	ExtBuilder::build().execute_with(|| {
+		ScheduleDeposit::set(5);
+		System::set_block_number(1);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, stepped(2, 10, 2, 10)));
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, stepped(2, 10, 4, 5)));
+		assert_eq!(deposit_held(ALICE), 10);
+
+		System::set_block_number(22);
+		assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB)));
+		assert_eq!(Vesting::vesting_schedules(BOB).len(), 1);
+		assert_eq!(frozen(BOB), 10);
+		assert_eq!(deposit_held(ALICE), 5);
+
+		System::set_block_number(42);
+		assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB)));
+		assert!(Vesting::vesting_schedules(BOB).is_empty());
+		assert_eq!(frozen(BOB), 0);
+		assert_eq!(deposit_held(ALICE), 0);
+		assert_eq!(PalletBalances::free_balance(ALICE), ALICE_BALANCE - 40);
+	});
+}
+
+#[test]
+fn vested_transfer_requires_schedule_deposit() {
+	ExtBuilder::build().execute_with(|| {
+		ScheduleDeposit::set(5);
+		System::set_block_number(1);
+
+		assert_noop!(
+			Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, stepped(2, 10, 1, 96)),
+			Error::<Runtime>::InsufficientBalanceToLock
+		);
+	});
+}
+
+#[test]
+fn frozen_vesting_releases_missed_vesting_on_unfreeze() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, stepped(2, 10, 2, 10)));
+
+		assert_noop!(
+			Vesting::freeze_vesting(RuntimeOrigin::signed(ALICE), BOB),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Vesting::freeze_vesting(RuntimeOrigin::root(), BOB));
+		System::assert_last_event(Event::VestingFrozen { who: BOB }.into());
+		assert_noop!(
+			Vesting::freeze_vesting(RuntimeOrigin::root(), BOB),
+			Error::<Runtime>::AlreadyFrozen
+		);
+
+		System::set_block_number(22);
+		assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB)));
+		assert_eq!(frozen(BOB), 20);
+		assert_eq!(Vesting::next_unlock(&BOB), None);
+		assert_noop!(
+			Vesting::transfer_schedule(RuntimeOrigin::signed(BOB), 0, CHARLIE),
+			Error::<Runtime>::AccountFrozen
+		);
+
+		// The schedule ended while frozen, so `on_idle` leaves it alone.
+		Vesting::on_idle(22, Weight::MAX);
+		assert_eq!(Vesting::vesting_schedules(BOB).len(), 1);
+
+		assert_ok!(Vesting::unfreeze_vesting(RuntimeOrigin::root(), BOB));
+		assert_eq!(frozen(BOB), 0);
+		System::assert_last_event(
+			Event::VestingUnfrozen { who: BOB, frozen_for: 21, extended: false }.into(),
+		);
+		assert_noop!(
+			Vesting::unfreeze_vesting(RuntimeOrigin::root(), BOB),
+			Error::<Runtime>::NotFrozen
+		);
+
+		// The schedule was indexed again when its account was unfrozen.
+		System::set_block_number(23);
+		Vesting::on_idle(23, Weight::MAX);
+		assert!(Vesting::vesting_schedules(BOB).is_empty());
+	});
+}
+
+#[test]
+fn frozen_vesting_is_extended_on_unfreeze() {
+	ExtBuilder::build().execute_with(|| {
+		ExtendFrozenVesting::set(true);
+		System::set_block_number(1);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, stepped(2, 10, 2, 10)));
+		assert_ok!(Vesting::vested_transfer_asset(RuntimeOrigin::signed(ALICE), ASSET, BOB, stepped(2, 10, 2, 10)));
+
+		System::set_block_number(12);
+		assert_ok!(Vesting::freeze_vesting(RuntimeOrigin::root(), BOB));
+		System::set_block_number(30);
+		assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB)));
+		assert_ok!(Vesting::claim_asset(RuntimeOrigin::signed(BOB), ASSET));
+		assert_eq!(frozen(BOB), 10);
+		assert_eq!(asset_frozen(BOB), 10);
+
+		assert_ok!(Vesting::unfreeze_vesting(RuntimeOrigin::root(), BOB));
+		assert_eq!(Vesting::vesting_schedules(BOB), vec![from_alice(stepped(20, 10, 2, 10))]);
+		assert_eq!(Vesting::asset_vesting_schedules(ASSET, BOB), vec![from_alice(stepped(20, 10, 2, 10))]);
+		assert_eq!(frozen(BOB), 10);
+		assert_eq!(asset_frozen(BOB), 10);
+
+		System::set_block_number(40);
+		assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB)));
+		assert_eq!(frozen(BOB), 0);
+	});
+}
+
+fn asset_frozen(who: AccountId) -> u64 {
+	<AssetsFreezer as fungibles::InspectFreeze<_>>::balance_frozen(
+		ASSET,
+		&FreezeReason::Vesting.into(),
+		&who,
+	)
+}
+
+#[test]
+fn vested_transfer_asset_works() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+
+		let schedule = stepped(2, 10, 2, 10);
+		assert_ok!(Vesting::vested_transfer_asset(RuntimeOrigin::signed(ALICE), ASSET, BOB, schedule.clone()));
+		assert_eq!(Vesting::asset_vesting_schedules(ASSET, BOB), vec![from_alice(schedule.clone())]);
+		assert!(Vesting::vesting_schedules(BOB).is_empty());
+		assert_eq!(Vesting::vesting_assets(BOB), vec![ASSET]);
+		assert_eq!(<Assets as fungibles::Inspect<_>>::balance(ASSET, &BOB), 20);
+		assert_eq!(asset_frozen(BOB), 20);
+		assert_eq!(frozen(BOB), 0);
+		assert_eq!(Vesting::asset_locked_balance(ASSET, &BOB), 20);
+		System::assert_last_event(
+			Event::AssetVestingScheduleAdded {
+				asset: ASSET,
+				from: ALICE,
+				to: BOB,
+				schedule: from_alice(schedule),
+			}
+			.into(),
+		);
+
+		System::set_block_number(12);
+		assert_eq!(Vesting::asset_vested_balance(ASSET, &BOB), 10);
+		assert_ok!(Vesting::claim_asset(RuntimeOrigin::signed(BOB), ASSET));
+		assert_eq!(asset_frozen(BOB), 10);
+		System::assert_last_event(
+			Event::AssetClaimed { asset: ASSET, who: BOB, unlocked: 10, locked: 10 }.into(),
+		);
+
+		System::set_block_number(22);
+		assert_ok!(Vesting::claim_asset(RuntimeOrigin::signed(BOB), ASSET));
+		assert_eq!(asset_frozen(BOB), 0);
+		assert!(Vesting::asset_vesting_schedules(ASSET, BOB).is_empty());
+		assert!(Vesting::vesting_assets(BOB).is_empty());
+	});
+}
+
+#[test]
+fn vested_transfer_asset_fails_if_revocable() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			Vesting::vested_transfer_asset(RuntimeOrigin::signed(ALICE), ASSET, BOB, revocable(stepped(2, 10, 2, 10))),
+			Error::<Runtime>::RevocableAssetSchedule
+		);
+	});
+}
+
+#[test]
+fn asset_min_vested_transfer_works() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(Vesting::min_vested_transfer(ASSET), 5);
+
+		assert_noop!(
+			Vesting::set_asset_min_vested_transfer(RuntimeOrigin::signed(ALICE), ASSET, Some(30)),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Vesting::set_asset_min_vested_transfer(RuntimeOrigin::root(), ASSET, Some(30)));
+		System::assert_last_event(Event::AssetMinVestedTransferSet { asset: ASSET, amount: Some(30) }.into());
+
+		assert_noop!(
+			Vesting::vested_transfer_asset(RuntimeOrigin::signed(ALICE), ASSET, BOB, stepped(2, 10, 2, 10)),
+			Error::<Runtime>::AmountLow
+		);
+		// The native minimum is unaffected.
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, stepped(2, 10, 2, 10)));
+		assert_ok!(Vesting::vested_transfer_asset(RuntimeOrigin::signed(ALICE), ASSET, BOB, stepped(2, 10, 3, 10)));
+	});
+}
+
+#[test]
+fn tge_relative_schedules_wait_for_tge_block() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		let relative = |offset| VestingSchedule {
+			tge_relative: true,
+			..with_curve(offset, VestingCurve::Linear { duration: 10, total: 20 })
+		};
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, relative(5)));
+		assert_eq!(ScheduleEnds::<Runtime>::iter().count(), 0);
+		assert_eq!(TgeScheduleEnds::<Runtime>::get(schedule_end_key(15u64), BOB), Some(()));
+
+		System::set_block_number(30);
+		assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB)));
+		assert_eq!(frozen(BOB), 20);
+		assert_eq!(Vesting::vested_balance(&BOB), 0);
+		assert_eq!(Vesting::next_unlock(&BOB), None);
+
+		assert_noop!(Vesting::set_tge_block(RuntimeOrigin::signed(BOB), 40), DispatchError::BadOrigin);
+		assert_ok!(Vesting::set_tge_block(RuntimeOrigin::root(), 40));
+		System::assert_last_event(Event::TgeBlockSet { block: 40 }.into());
+		assert_noop!(Vesting::set_tge_block(RuntimeOrigin::root(), 45), Error::<Runtime>::TgeAlreadySet);
+
+		// The schedule now runs from block 45 to 55.
+		System::set_block_number(50);
+		assert_eq!(Vesting::vested_balance(&BOB), 10);
+		assert_eq!(Vesting::next_unlock(&BOB), Some(51));
+		assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB)));
+		assert_eq!(frozen(BOB), 10);
+
+		// New grants relative to the TGE must still start in the future.
+		assert_noop!(
+			Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, relative(10)),
+			Error::<Runtime>::InvalidVestingStart
+		);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, relative(11)));
+		assert_eq!(TgeScheduleEnds::<Runtime>::get(schedule_end_key(21u64), BOB), Some(()));
+
+		// The first schedule is due for cleanup once it ends at block 55.
+		Vesting::on_idle(50, Weight::MAX);
+		assert_eq!(Vesting::vesting_schedules(BOB).len(), 2);
+		System::set_block_number(55);
+		Vesting::on_idle(55, Weight::MAX);
+		assert_eq!(Vesting::vesting_schedules(BOB), vec![from_alice(relative(11))]);
+		assert_eq!(TgeScheduleEnds::<Runtime>::get(schedule_end_key(15u64), BOB), None);
+	});
+}
+
+#[test]
+fn set_tge_block_fails_if_in_the_past() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(10);
+		assert_noop!(Vesting::set_tge_block(RuntimeOrigin::root(), 9), Error::<Runtime>::TgeBlockInPast);
+
+		assert_ok!(Vesting::set_tge_block(RuntimeOrigin::root(), 10));
+		assert_eq!(Vesting::tge_block(), Some(10));
+	});
+}
+
+#[test]
+fn accelerate_vesting_works() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		let schedule = with_curve(10, VestingCurve::Linear { duration: 80, total: 80 });
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, schedule));
+
+		System::set_block_number(50);
+		let speed_up = Acceleration::SpeedUp(2);
+		assert_noop!(Vesting::accelerate_vesting(RuntimeOrigin::signed(ALICE), BOB, speed_up), DispatchError::BadOrigin);
+		assert_noop!(
+			Vesting::accelerate_vesting(RuntimeOrigin::root(), BOB, Acceleration::SpeedUp(1)),
+			Error::<Runtime>::InvalidAcceleration
+		);
+		assert_noop!(
+			Vesting::accelerate_vesting(RuntimeOrigin::root(), BOB, Acceleration::VestNow(Percent::zero())),
+			Error::<Runtime>::InvalidAcceleration
+		);
+		assert_noop!(Vesting::accelerate_vesting(RuntimeOrigin::root(), CHARLIE, speed_up), Error::<Runtime>::NotVested);
+
+		// 40 of 80 have vested, the other 40 now vest over 20 blocks instead of 40.
+		assert_ok!(Vesting::accelerate_vesting(RuntimeOrigin::root(), BOB, speed_up));
+		let sped_up = from_alice(with_curve(30, VestingCurve::Linear { duration: 40, total: 80 }));
+		assert_eq!(Vesting::vesting_schedules(BOB), vec![sped_up]);
+		assert_eq!(frozen(BOB), 40);
+		assert_eq!(ScheduleEnds::<Runtime>::get(schedule_end_key(70u64), BOB), Some(()));
+		System::assert_last_event(Event::VestingAccelerated { who: BOB, acceleration: speed_up, unlocked: 0 }.into());
+
+		let vest_half = Acceleration::VestNow(Percent::from_percent(50));
+		assert_ok!(Vesting::accelerate_vesting(RuntimeOrigin::root(), BOB, vest_half));
+		assert_eq!(frozen(BOB), 20);
+		assert_eq!(Vesting::vesting_schedules(BOB)[0].total_amount(), Some(80));
+		assert_eq!(Vesting::acceleration_count(BOB), 2);
+		assert_eq!(
+			Vesting::vesting_accelerations(BOB, 0),
+			Some(AccelerationRecord { at: 50, acceleration: speed_up, unlocked: 0 })
+		);
+		assert_eq!(
+			Vesting::vesting_accelerations(BOB, 1),
+			Some(AccelerationRecord { at: 50, acceleration: vest_half, unlocked: 20 })
+		);
+
+		System::set_block_number(60);
+		assert_eq!(Vesting::locked_balance(&BOB), 10);
+
+		assert_ok!(Vesting::accelerate_vesting(
+			RuntimeOrigin::root(),
+			BOB,
+			Acceleration::VestNow(Percent::from_percent(100))
+		));
+		assert_eq!(frozen(BOB), 0);
+	});
+}
+
+#[test]
+fn accelerate_vesting_keeps_every_record() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, stepped(2, 10, 2, 10)));
+
+		let speed_up = Acceleration::SpeedUp(2);
+		for block in 1..=20 {
+			System::set_block_number(block);
+			assert_ok!(Vesting::accelerate_vesting(RuntimeOrigin::root(), BOB, speed_up));
+		}
+		assert_eq!(Vesting::acceleration_count(BOB), 20);
+		for (index, block) in (0..20).zip(1..=20) {
+			assert_eq!(Vesting::vesting_accelerations(BOB, index).map(|record| record.at), Some(block));
+		}
+		assert_eq!(Vesting::vesting_accelerations(BOB, 20), None);
+	});
+}
+
+#[test]
+fn next_unlock_works() {
+	let schedule = stepped(10, 5, 2, 10);
+	for (now, next) in [(0, Some(15)), (10, Some(15)), (14, Some(15)), (15, Some(20)), (20, None)] {
+		assert_eq!(schedule.next_unlock(now), next);
+	}
+
+	let schedule = with_curve(10, VestingCurve::Cliff { cliff: 4, duration: 8, total: 80 });
+	for (now, next) in [(0, Some(14)), (13, Some(14)), (14, Some(15)), (17, Some(18)), (18, None)] {
+		assert_eq!(schedule.next_unlock(now), next);
+	}
+
+	let schedule = with_curve(10, VestingCurve::Linear { duration: 8, total: 80 });
+	for (now, next) in [(0, Some(11)), (10, Some(11)), (17, Some(18)), (18, None)] {
+		assert_eq!(schedule.next_unlock(now), next);
+	}
+
+	let schedule =
+		with_curve(10, VestingCurve::Tranches(BoundedVec::truncate_from(vec![(0, 10), (5, 20)])));
+	for (now, next) in [(0, Some(10)), (10, Some(15)), (15, None)] {
+		assert_eq!(schedule.next_unlock(now), next);
+	}
+}
+
+#[test]
+fn vesting_queries_work() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, stepped(2, 10, 2, 10)));
+		assert_ok!(Vesting::vested_transfer(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			with_curve(5, VestingCurve::Linear { duration: 10, total: 10 })
+		));
+		assert_eq!(Vesting::next_unlock(&BOB), Some(6));
+
+		System::set_block_number(15);
+		assert_eq!(Vesting::vested_balance(&BOB), 20);
+		assert_eq!(Vesting::locked_balance(&BOB), 10);
+		assert_eq!(Vesting::claimable_balance(&BOB), 20);
+		assert_eq!(Vesting::next_unlock(&BOB), Some(22));
+
+		assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB)));
+		assert_eq!(Vesting::claimable_balance(&BOB), 0);
+
+		System::set_block_number(22);
+		assert_eq!(Vesting::next_unlock(&BOB), None);
+		assert_eq!(Vesting::claimable_balance(&BOB), 10);
+	});
+}
+
+#[test]
+fn unlock_calendar_works() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(Vesting::unlock_calendar(&BOB, 10), vec![]);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, stepped(10, 10, 2, 10)));
+		assert_ok!(Vesting::vested_transfer(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			with_curve(15, VestingCurve::Linear { duration: 20, total: 20 })
+		));
+
+		// The linear release is summed up at the steps and where it ends.
+		assert_eq!(Vesting::unlock_calendar(&BOB, 10), vec![(20, 15, 15), (30, 20, 35), (35, 5, 40)]);
+		assert_eq!(Vesting::unlock_calendar(&BOB, 2), vec![(20, 15, 15), (30, 20, 35)]);
+
+		System::set_block_number(25);
+		assert_eq!(Vesting::unlock_calendar(&BOB, 10), vec![(30, 15, 35), (35, 5, 40)]);
+
+		assert_ok!(Vesting::freeze_vesting(RuntimeOrigin::root(), BOB));
+		assert_eq!(Vesting::unlock_calendar(&BOB, 10), vec![]);
+	});
+}
+
+#[test]
+fn unix_time_provider_counts_milliseconds() {
+	struct Clock;
+	impl UnixTime for Clock {
+		fn now() -> core::time::Duration {
+			core::time::Duration::from_secs(90)
+		}
+	}
+
+	assert_eq!(UnixTimeProvider::<Clock>::current_block_number(), 90_000);
+}
+
+#[test]
+fn on_idle_prunes_completed_schedules() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, stepped(2, 10, 2, 10)));
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, stepped(2, 10, 4, 5)));
+		assert_eq!(ScheduleEnds::<Runtime>::get(schedule_end_key(22u64), BOB), Some(()));
+		assert_eq!(ScheduleEnds::<Runtime>::get(schedule_end_key(42u64), BOB), Some(()));
+
+		System::set_block_number(22);
+		Vesting::on_idle(22, Weight::MAX);
+		assert_eq!(Vesting::vesting_schedules(BOB), vec![from_alice(stepped(2, 10, 4, 5))]);
+		assert_eq!(frozen(BOB), 10);
+		assert_eq!(ScheduleEnds::<Runtime>::get(schedule_end_key(22u64), BOB), None);
+
+		System::set_block_number(42);
+		Vesting::on_idle(42, Weight::MAX);
+		assert_eq!(Vesting::vesting_schedules(BOB), vec![]);
+		assert_eq!(frozen(BOB), 0);
+		System::assert_last_event(Event::VestingCompleted { who: BOB }.into());
+	});
+}
+
+#[test]
+fn on_idle_prunes_completed_asset_schedules() {
+	ExtBuilder::build().execute_with(|| {
+		ScheduleDeposit::set(5);
+		System::set_block_number(1);
+		assert_ok!(Vesting::vested_transfer_asset(RuntimeOrigin::signed(ALICE), ASSET, BOB, stepped(2, 10, 2, 10)));
+		assert_eq!(ScheduleEnds::<Runtime>::get(schedule_end_key(22u64), BOB), Some(()));
+		assert_eq!(deposit_held(ALICE), 5);
+
+		System::set_block_number(22);
+		Vesting::on_idle(22, Weight::MAX);
+		assert!(Vesting::asset_vesting_schedules(ASSET, BOB).is_empty());
+		assert!(Vesting::vesting_assets(BOB).is_empty());
+		assert_eq!(asset_frozen(BOB), 0);
+		assert_eq!(deposit_held(ALICE), 0);
+	});
+}
+
+#[test]
+fn on_idle_respects_remaining_weight() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, stepped(2, 10, 2, 10)));
+
+		System::set_block_number(22);
+		assert_eq!(Vesting::on_idle(22, Weight::zero()), Weight::zero());
+
+		// Enough to find the account due at block 22, but not to prune it.
+		Vesting::on_idle(22, <() as WeightInfo>::cleanup_step());
+		assert_eq!(Vesting::vesting_schedules(BOB).len(), 1);
+		assert_eq!(ScheduleEnds::<Runtime>::get(schedule_end_key(22u64), BOB), Some(()));
+
+		Vesting::on_idle(22, Weight::MAX);
+		assert_eq!(Vesting::vesting_schedules(BOB), vec![]);
+		assert_eq!(ScheduleEnds::<Runtime>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn on_idle_seeks_to_the_next_schedule_end() {
+	ExtBuilder::build().execute_with(|| {
+		// A millisecond clock puts ends far apart, cleanup must not walk the time in between.
+		System::set_block_number(1);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, stepped(2, 1_000_000_000, 2, 10)));
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), CHARLIE, stepped(2, 10, 2, 10)));
+
+		System::set_block_number(2_000_000_002);
+		let weight = <() as WeightInfo>::cleanup_step()
+			.saturating_add(<() as WeightInfo>::cleanup_account(2).saturating_mul(3))
+			.saturating_mul(2);
+		Vesting::on_idle(2_000_000_002, weight);
+		assert_eq!(Vesting::vesting_schedules(BOB), vec![]);
+		assert_eq!(Vesting::vesting_schedules(CHARLIE), vec![]);
+	});
+}
+
+#[test]
+fn genesis_config_works() {
+	ExtBuilder::build_with_vesting(vec![(ALICE, stepped(1, 10, 2, 10)), (ALICE, stepped(5, 10, 1, 30))])
+		.execute_with(|| {
+			assert_eq!(Vesting::vesting_schedules(ALICE), vec![stepped(1, 10, 2, 10), stepped(5, 10, 1, 30)]);
+			assert_eq!(frozen(ALICE), 50);
+			assert_eq!(PalletBalances::free_balance(ALICE), ALICE_BALANCE);
+		});
+}
+
+#[test]
+#[should_panic(expected = "Genesis vesting schedule must be valid")]
+fn genesis_config_rejects_invalid_schedule() {
+	ExtBuilder::build_with_vesting(vec![(ALICE, stepped(1, 0, 2, 10))]);
+}
+
+#[test]
+#[should_panic(expected = "Genesis vesting account does not have enough balance to lock")]
+fn genesis_config_rejects_unfunded_schedule() {
+	ExtBuilder::build_with_vesting(vec![(BOB, stepped(1, 10, 2, 10))]);
+}
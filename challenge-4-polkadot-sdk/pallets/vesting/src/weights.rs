@@ -0,0 +1,1126 @@
+
+//! Autogenerated weights for `pallet_vesting`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 47.0.0
+//! DATE: 2026-10-18, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
+
+// Executed Command:
+// frame-omni-bencher
+// v1
+// benchmark
+// pallet
+// --runtime
+// target/release/wbuild/minimal-template-runtime/minimal_template_runtime.compact.compressed.wasm
+// --pallet
+// pallet_vesting
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --template
+// .maintain/frame-weight-template.hbs
+// --output
+// pallets/vesting/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use polkadot_sdk::{frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}}, frame_system};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_vesting`.
+pub trait WeightInfo {
+	fn vested_transfer(s: u32, ) -> Weight;
+	fn claim(s: u32, ) -> Weight;
+	fn update_vesting_schedules(s: u32, ) -> Weight;
+	fn update_vesting_schedule(s: u32, ) -> Weight;
+	fn force_remove_vesting_schedule(s: u32, ) -> Weight;
+	fn merge_schedules(s: u32, ) -> Weight;
+	fn split_schedule(s: u32, ) -> Weight;
+	fn revoke(s: u32, ) -> Weight;
+	fn set_revoke_beneficiary() -> Weight;
+	fn transfer_schedule(s: u32, ) -> Weight;
+	fn release_milestone(s: u32, ) -> Weight;
+	fn batch_vested_transfer(n: u32, ) -> Weight;
+	fn set_accepted_grantors(g: u32, ) -> Weight;
+	fn freeze_vesting() -> Weight;
+	fn unfreeze_vesting(s: u32, a: u32, ) -> Weight;
+	fn vested_transfer_asset(s: u32, ) -> Weight;
+	fn claim_asset(s: u32, ) -> Weight;
+	fn set_asset_min_vested_transfer() -> Weight;
+	fn set_tge_block() -> Weight;
+	fn accelerate_vesting(s: u32, ) -> Weight;
+	fn cleanup_step() -> Weight;
+	fn cleanup_account(s: u32, ) -> Weight;
+	fn migrate_v1_to_v2_step(s: u32, ) -> Weight;
+}
+
+/// Weights for `pallet_vesting` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Vesting::AcceptedGrantors` (r:1 w:0)
+	/// Proof: `Vesting::AcceptedGrantors` (`max_values`: None, `max_size`: Some(561), added: 3036, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::FrozenSince` (r:1 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1750), added: 4225, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::ScheduleEnds` (r:0 w:1)
+	/// Proof: `Vesting::ScheduleEnds` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 9]`.
+	fn vested_transfer(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1014 + s * (68 ±0)`
+		//  Estimated: `6004`
+		// Minimum execution time: 179_371_000 picoseconds.
+		Weight::from_parts(295_670_297, 6004)
+			// Standard Error: 175_841
+			.saturating_add(Weight::from_parts(2_757_100, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Vesting::FrozenSince` (r:1 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1750), added: 4225, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 10]`.
+	fn claim(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `442 + s * (68 ±0)`
+		//  Estimated: `6004`
+		// Minimum execution time: 182_732_000 picoseconds.
+		Weight::from_parts(174_713_652, 6004)
+			// Standard Error: 914_084
+			.saturating_add(Weight::from_parts(45_990_797, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Vesting::FrozenSince` (r:1 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1750), added: 4225, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::ScheduleEnds` (r:0 w:1)
+	/// Proof: `Vesting::ScheduleEnds` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 10]`.
+	fn update_vesting_schedules(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `428 + s * (68 ±0)`
+		//  Estimated: `6004`
+		// Minimum execution time: 69_835_000 picoseconds.
+		Weight::from_parts(108_539_841, 6004)
+			// Standard Error: 207_745
+			.saturating_add(Weight::from_parts(6_620_427, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::FrozenSince` (r:1 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1750), added: 4225, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::ScheduleEnds` (r:0 w:1)
+	/// Proof: `Vesting::ScheduleEnds` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 10]`.
+	fn update_vesting_schedule(_s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `428 + s * (68 ±0)`
+		//  Estimated: `6004`
+		// Minimum execution time: 64_462_000 picoseconds.
+		Weight::from_parts(113_151_132, 6004)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::FrozenSince` (r:1 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1750), added: 4225, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 10]`.
+	fn force_remove_vesting_schedule(_s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `428 + s * (68 ±0)`
+		//  Estimated: `6004`
+		// Minimum execution time: 62_933_000 picoseconds.
+		Weight::from_parts(117_369_499, 6004)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Vesting::FrozenSince` (r:1 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1750), added: 4225, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::ScheduleEnds` (r:0 w:1)
+	/// Proof: `Vesting::ScheduleEnds` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 10]`.
+	fn merge_schedules(_s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `279 + s * (68 ±0)`
+		//  Estimated: `6004`
+		// Minimum execution time: 65_252_000 picoseconds.
+		Weight::from_parts(127_642_570, 6004)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Vesting::FrozenSince` (r:1 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1750), added: 4225, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::ScheduleEnds` (r:0 w:1)
+	/// Proof: `Vesting::ScheduleEnds` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 9]`.
+	fn split_schedule(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `279 + s * (68 ±0)`
+		//  Estimated: `6004`
+		// Minimum execution time: 58_637_000 picoseconds.
+		Weight::from_parts(103_775_523, 6004)
+			// Standard Error: 145_475
+			.saturating_add(Weight::from_parts(571_329, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Vesting::FrozenSince` (r:1 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1750), added: 4225, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::RevokeBeneficiary` (r:1 w:0)
+	/// Proof: `Vesting::RevokeBeneficiary` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 10]`.
+	fn revoke(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `645 + s * (68 ±0)`
+		//  Estimated: `6148`
+		// Minimum execution time: 129_783_000 picoseconds.
+		Weight::from_parts(218_063_083, 6148)
+			// Standard Error: 200_500
+			.saturating_add(Weight::from_parts(140_228, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Vesting::RevokeBeneficiary` (r:0 w:1)
+	/// Proof: `Vesting::RevokeBeneficiary` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn set_revoke_beneficiary() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 19_938_000 picoseconds.
+		Weight::from_parts(22_086_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vesting::FrozenSince` (r:2 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::AcceptedGrantors` (r:1 w:0)
+	/// Proof: `Vesting::AcceptedGrantors` (`max_values`: None, `max_size`: Some(561), added: 3036, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:2 w:2)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:2 w:2)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:2 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1750), added: 4225, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingAssets` (r:1 w:0)
+	/// Proof: `Vesting::VestingAssets` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::ScheduleEnds` (r:0 w:1)
+	/// Proof: `Vesting::ScheduleEnds` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 10]`.
+	fn transfer_schedule(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1033 + s * (136 ±0)`
+		//  Estimated: `11018`
+		// Minimum execution time: 171_958_000 picoseconds.
+		Weight::from_parts(282_361_891, 11018)
+			// Standard Error: 499_413
+			.saturating_add(Weight::from_parts(228_845, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Vesting::FrozenSince` (r:1 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1750), added: 4225, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 10]`.
+	fn release_milestone(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `429 + s * (68 ±0)`
+		//  Estimated: `6004`
+		// Minimum execution time: 67_639_000 picoseconds.
+		Weight::from_parts(122_454_353, 6004)
+			// Standard Error: 119_980
+			.saturating_add(Weight::from_parts(1_161_356, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::AcceptedGrantors` (r:256 w:0)
+	/// Proof: `Vesting::AcceptedGrantors` (`max_values`: None, `max_size`: Some(561), added: 3036, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:256 w:256)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:256 w:256)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::FrozenSince` (r:256 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:256 w:256)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:256 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1750), added: 4225, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::ScheduleEnds` (r:0 w:256)
+	/// Proof: `Vesting::ScheduleEnds` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn batch_vested_transfer(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `130 + n * (1413 ±0)`
+		//  Estimated: `3534 + n * (5014 ±0)`
+		// Minimum execution time: 261_468_000 picoseconds.
+		Weight::from_parts(313_237_000, 3534)
+			// Standard Error: 528_599
+			.saturating_add(Weight::from_parts(269_793_339, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5014).saturating_mul(n.into()))
+	}
+	/// Storage: `Vesting::AcceptedGrantors` (r:0 w:1)
+	/// Proof: `Vesting::AcceptedGrantors` (`max_values`: None, `max_size`: Some(561), added: 3036, mode: `MaxEncodedLen`)
+	/// The range of component `g` is `[0, 16]`.
+	fn set_accepted_grantors(g: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 10_897_000 picoseconds.
+		Weight::from_parts(18_908_991, 0)
+			// Standard Error: 36_268
+			.saturating_add(Weight::from_parts(96_446, 0).saturating_mul(g.into()))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vesting::FrozenSince` (r:1 w:1)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn freeze_vesting() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `92`
+		//  Estimated: `3517`
+		// Minimum execution time: 25_357_000 picoseconds.
+		Weight::from_parts(33_179_000, 3517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vesting::FrozenSince` (r:1 w:1)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1750), added: 4225, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingAssets` (r:1 w:0)
+	/// Proof: `Vesting::VestingAssets` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::AssetVestingSchedules` (r:8 w:8)
+	/// Proof: `Vesting::AssetVestingSchedules` (`max_values`: None, `max_size`: Some(2559), added: 5034, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:8 w:8)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:8 w:8)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::ScheduleEnds` (r:0 w:1)
+	/// Proof: `Vesting::ScheduleEnds` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 10]`.
+	/// The range of component `a` is `[0, 8]`.
+	fn unfreeze_vesting(_s: u32, a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `621 + a * (935 ±0) + s * (68 ±0)`
+		//  Estimated: `6004 + a * (5034 ±0)`
+		// Minimum execution time: 94_576_000 picoseconds.
+		Weight::from_parts(219_449_442, 6004)
+			// Standard Error: 453_955
+			.saturating_add(Weight::from_parts(92_954_259, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 5034).saturating_mul(a.into()))
+	}
+	/// Storage: `Vesting::AcceptedGrantors` (r:1 w:0)
+	/// Proof: `Vesting::AcceptedGrantors` (`max_values`: None, `max_size`: Some(561), added: 3036, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::AssetMinVestedTransfer` (r:1 w:0)
+	/// Proof: `Vesting::AssetMinVestedTransfer` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(186), added: 2661, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:2 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::AssetVestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::AssetVestingSchedules` (`max_values`: None, `max_size`: Some(2559), added: 5034, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingAssets` (r:1 w:1)
+	/// Proof: `Vesting::VestingAssets` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::FrozenSince` (r:1 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::ScheduleEnds` (r:0 w:1)
+	/// Proof: `Vesting::ScheduleEnds` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 9]`.
+	fn vested_transfer_asset(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1465 + s * (68 ±0)`
+		//  Estimated: `6176`
+		// Minimum execution time: 202_222_000 picoseconds.
+		Weight::from_parts(334_202_915, 6176)
+			// Standard Error: 223_323
+			.saturating_add(Weight::from_parts(4_532_846, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: `Vesting::FrozenSince` (r:1 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::AssetVestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::AssetVestingSchedules` (`max_values`: None, `max_size`: Some(2559), added: 5034, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 10]`.
+	fn claim_asset(_s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `451 + s * (68 ±0)`
+		//  Estimated: `6024`
+		// Minimum execution time: 64_031_000 picoseconds.
+		Weight::from_parts(122_374_798, 6024)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Vesting::AssetMinVestedTransfer` (r:0 w:1)
+	/// Proof: `Vesting::AssetMinVestedTransfer` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn set_asset_min_vested_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 16_574_000 picoseconds.
+		Weight::from_parts(19_701_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vesting::TgeBlock` (r:1 w:1)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_tge_block() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `116`
+		//  Estimated: `1489`
+		// Minimum execution time: 24_091_000 picoseconds.
+		Weight::from_parts(27_542_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vesting::FrozenSince` (r:1 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1750), added: 4225, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::AccelerationCount` (r:1 w:1)
+	/// Proof: `Vesting::AccelerationCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::ScheduleEnds` (r:0 w:1)
+	/// Proof: `Vesting::ScheduleEnds` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingAccelerations` (r:0 w:1)
+	/// Proof: `Vesting::VestingAccelerations` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 10]`.
+	fn accelerate_vesting(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `428 + s * (68 ±0)`
+		//  Estimated: `6004`
+		// Minimum execution time: 121_284_000 picoseconds.
+		Weight::from_parts(146_006_113, 6004)
+			// Standard Error: 110_423
+			.saturating_add(Weight::from_parts(4_431_530, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::ScheduleEnds` (r:2 w:0)
+	/// Proof: `Vesting::ScheduleEnds` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeScheduleEnds` (r:2 w:0)
+	/// Proof: `Vesting::TgeScheduleEnds` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn cleanup_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `297`
+		//  Estimated: `6052`
+		// Minimum execution time: 37_766_000 picoseconds.
+		Weight::from_parts(41_599_000, 6052)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+	}
+	/// Storage: `Vesting::FrozenSince` (r:1 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1750), added: 4225, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingAssets` (r:1 w:0)
+	/// Proof: `Vesting::VestingAssets` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 10]`.
+	fn cleanup_account(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `428 + s * (68 ±0)`
+		//  Estimated: `6004`
+		// Minimum execution time: 65_148_000 picoseconds.
+		Weight::from_parts(107_822_050, 6004)
+			// Standard Error: 78_108
+			.saturating_add(Weight::from_parts(853_860, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: UNKNOWN KEY `0x5f27b51b5ec208ee9cb25b55d87282434e7b9012096b41c4eb3aaf947f6ea429` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x5f27b51b5ec208ee9cb25b55d87282434e7b9012096b41c4eb3aaf947f6ea429` (r:1 w:0)
+	/// Storage: `Vesting::VestingSchedules` (r:2 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::ScheduleEnds` (r:0 w:1)
+	/// Proof: `Vesting::ScheduleEnds` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 10]`.
+	fn migrate_v1_to_v2_step(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `155 + s * (20 ±0)`
+		//  Estimated: `11018 + s * (20 ±0)`
+		// Minimum execution time: 28_215_000 picoseconds.
+		Weight::from_parts(33_634_098, 11018)
+			// Standard Error: 62_015
+			.saturating_add(Weight::from_parts(3_250_579, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 20).saturating_mul(s.into()))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `Vesting::AcceptedGrantors` (r:1 w:0)
+	/// Proof: `Vesting::AcceptedGrantors` (`max_values`: None, `max_size`: Some(561), added: 3036, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::FrozenSince` (r:1 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1750), added: 4225, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::ScheduleEnds` (r:0 w:1)
+	/// Proof: `Vesting::ScheduleEnds` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 9]`.
+	fn vested_transfer(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1014 + s * (68 ±0)`
+		//  Estimated: `6004`
+		// Minimum execution time: 179_371_000 picoseconds.
+		Weight::from_parts(295_670_297, 6004)
+			// Standard Error: 175_841
+			.saturating_add(Weight::from_parts(2_757_100, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Vesting::FrozenSince` (r:1 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1750), added: 4225, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 10]`.
+	fn claim(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `442 + s * (68 ±0)`
+		//  Estimated: `6004`
+		// Minimum execution time: 182_732_000 picoseconds.
+		Weight::from_parts(174_713_652, 6004)
+			// Standard Error: 914_084
+			.saturating_add(Weight::from_parts(45_990_797, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Vesting::FrozenSince` (r:1 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1750), added: 4225, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::ScheduleEnds` (r:0 w:1)
+	/// Proof: `Vesting::ScheduleEnds` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 10]`.
+	fn update_vesting_schedules(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `428 + s * (68 ±0)`
+		//  Estimated: `6004`
+		// Minimum execution time: 69_835_000 picoseconds.
+		Weight::from_parts(108_539_841, 6004)
+			// Standard Error: 207_745
+			.saturating_add(Weight::from_parts(6_620_427, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::FrozenSince` (r:1 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1750), added: 4225, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::ScheduleEnds` (r:0 w:1)
+	/// Proof: `Vesting::ScheduleEnds` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 10]`.
+	fn update_vesting_schedule(_s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `428 + s * (68 ±0)`
+		//  Estimated: `6004`
+		// Minimum execution time: 64_462_000 picoseconds.
+		Weight::from_parts(113_151_132, 6004)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::FrozenSince` (r:1 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1750), added: 4225, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 10]`.
+	fn force_remove_vesting_schedule(_s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `428 + s * (68 ±0)`
+		//  Estimated: `6004`
+		// Minimum execution time: 62_933_000 picoseconds.
+		Weight::from_parts(117_369_499, 6004)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Vesting::FrozenSince` (r:1 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1750), added: 4225, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::ScheduleEnds` (r:0 w:1)
+	/// Proof: `Vesting::ScheduleEnds` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 10]`.
+	fn merge_schedules(_s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `279 + s * (68 ±0)`
+		//  Estimated: `6004`
+		// Minimum execution time: 65_252_000 picoseconds.
+		Weight::from_parts(127_642_570, 6004)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Vesting::FrozenSince` (r:1 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1750), added: 4225, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::ScheduleEnds` (r:0 w:1)
+	/// Proof: `Vesting::ScheduleEnds` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 9]`.
+	fn split_schedule(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `279 + s * (68 ±0)`
+		//  Estimated: `6004`
+		// Minimum execution time: 58_637_000 picoseconds.
+		Weight::from_parts(103_775_523, 6004)
+			// Standard Error: 145_475
+			.saturating_add(Weight::from_parts(571_329, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Vesting::FrozenSince` (r:1 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1750), added: 4225, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::RevokeBeneficiary` (r:1 w:0)
+	/// Proof: `Vesting::RevokeBeneficiary` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 10]`.
+	fn revoke(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `645 + s * (68 ±0)`
+		//  Estimated: `6148`
+		// Minimum execution time: 129_783_000 picoseconds.
+		Weight::from_parts(218_063_083, 6148)
+			// Standard Error: 200_500
+			.saturating_add(Weight::from_parts(140_228, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Vesting::RevokeBeneficiary` (r:0 w:1)
+	/// Proof: `Vesting::RevokeBeneficiary` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn set_revoke_beneficiary() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 19_938_000 picoseconds.
+		Weight::from_parts(22_086_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vesting::FrozenSince` (r:2 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::AcceptedGrantors` (r:1 w:0)
+	/// Proof: `Vesting::AcceptedGrantors` (`max_values`: None, `max_size`: Some(561), added: 3036, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:2 w:2)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:2 w:2)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:2 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1750), added: 4225, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingAssets` (r:1 w:0)
+	/// Proof: `Vesting::VestingAssets` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::ScheduleEnds` (r:0 w:1)
+	/// Proof: `Vesting::ScheduleEnds` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 10]`.
+	fn transfer_schedule(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1033 + s * (136 ±0)`
+		//  Estimated: `11018`
+		// Minimum execution time: 171_958_000 picoseconds.
+		Weight::from_parts(282_361_891, 11018)
+			// Standard Error: 499_413
+			.saturating_add(Weight::from_parts(228_845, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Vesting::FrozenSince` (r:1 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1750), added: 4225, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 10]`.
+	fn release_milestone(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `429 + s * (68 ±0)`
+		//  Estimated: `6004`
+		// Minimum execution time: 67_639_000 picoseconds.
+		Weight::from_parts(122_454_353, 6004)
+			// Standard Error: 119_980
+			.saturating_add(Weight::from_parts(1_161_356, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::AcceptedGrantors` (r:256 w:0)
+	/// Proof: `Vesting::AcceptedGrantors` (`max_values`: None, `max_size`: Some(561), added: 3036, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:256 w:256)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:256 w:256)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::FrozenSince` (r:256 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:256 w:256)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:256 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1750), added: 4225, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::ScheduleEnds` (r:0 w:256)
+	/// Proof: `Vesting::ScheduleEnds` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn batch_vested_transfer(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `130 + n * (1413 ±0)`
+		//  Estimated: `3534 + n * (5014 ±0)`
+		// Minimum execution time: 261_468_000 picoseconds.
+		Weight::from_parts(313_237_000, 3534)
+			// Standard Error: 528_599
+			.saturating_add(Weight::from_parts(269_793_339, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5014).saturating_mul(n.into()))
+	}
+	/// Storage: `Vesting::AcceptedGrantors` (r:0 w:1)
+	/// Proof: `Vesting::AcceptedGrantors` (`max_values`: None, `max_size`: Some(561), added: 3036, mode: `MaxEncodedLen`)
+	/// The range of component `g` is `[0, 16]`.
+	fn set_accepted_grantors(g: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 10_897_000 picoseconds.
+		Weight::from_parts(18_908_991, 0)
+			// Standard Error: 36_268
+			.saturating_add(Weight::from_parts(96_446, 0).saturating_mul(g.into()))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vesting::FrozenSince` (r:1 w:1)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn freeze_vesting() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `92`
+		//  Estimated: `3517`
+		// Minimum execution time: 25_357_000 picoseconds.
+		Weight::from_parts(33_179_000, 3517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vesting::FrozenSince` (r:1 w:1)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1750), added: 4225, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingAssets` (r:1 w:0)
+	/// Proof: `Vesting::VestingAssets` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::AssetVestingSchedules` (r:8 w:8)
+	/// Proof: `Vesting::AssetVestingSchedules` (`max_values`: None, `max_size`: Some(2559), added: 5034, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:8 w:8)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:8 w:8)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::ScheduleEnds` (r:0 w:1)
+	/// Proof: `Vesting::ScheduleEnds` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 10]`.
+	/// The range of component `a` is `[0, 8]`.
+	fn unfreeze_vesting(_s: u32, a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `621 + a * (935 ±0) + s * (68 ±0)`
+		//  Estimated: `6004 + a * (5034 ±0)`
+		// Minimum execution time: 94_576_000 picoseconds.
+		Weight::from_parts(219_449_442, 6004)
+			// Standard Error: 453_955
+			.saturating_add(Weight::from_parts(92_954_259, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 5034).saturating_mul(a.into()))
+	}
+	/// Storage: `Vesting::AcceptedGrantors` (r:1 w:0)
+	/// Proof: `Vesting::AcceptedGrantors` (`max_values`: None, `max_size`: Some(561), added: 3036, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::AssetMinVestedTransfer` (r:1 w:0)
+	/// Proof: `Vesting::AssetMinVestedTransfer` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(186), added: 2661, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:2 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::AssetVestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::AssetVestingSchedules` (`max_values`: None, `max_size`: Some(2559), added: 5034, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingAssets` (r:1 w:1)
+	/// Proof: `Vesting::VestingAssets` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::FrozenSince` (r:1 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::ScheduleEnds` (r:0 w:1)
+	/// Proof: `Vesting::ScheduleEnds` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 9]`.
+	fn vested_transfer_asset(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1465 + s * (68 ±0)`
+		//  Estimated: `6176`
+		// Minimum execution time: 202_222_000 picoseconds.
+		Weight::from_parts(334_202_915, 6176)
+			// Standard Error: 223_323
+			.saturating_add(Weight::from_parts(4_532_846, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `Vesting::FrozenSince` (r:1 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::AssetVestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::AssetVestingSchedules` (`max_values`: None, `max_size`: Some(2559), added: 5034, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 10]`.
+	fn claim_asset(_s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `451 + s * (68 ±0)`
+		//  Estimated: `6024`
+		// Minimum execution time: 64_031_000 picoseconds.
+		Weight::from_parts(122_374_798, 6024)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Vesting::AssetMinVestedTransfer` (r:0 w:1)
+	/// Proof: `Vesting::AssetMinVestedTransfer` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn set_asset_min_vested_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 16_574_000 picoseconds.
+		Weight::from_parts(19_701_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vesting::TgeBlock` (r:1 w:1)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_tge_block() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `116`
+		//  Estimated: `1489`
+		// Minimum execution time: 24_091_000 picoseconds.
+		Weight::from_parts(27_542_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vesting::FrozenSince` (r:1 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1750), added: 4225, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::AccelerationCount` (r:1 w:1)
+	/// Proof: `Vesting::AccelerationCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::ScheduleEnds` (r:0 w:1)
+	/// Proof: `Vesting::ScheduleEnds` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingAccelerations` (r:0 w:1)
+	/// Proof: `Vesting::VestingAccelerations` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 10]`.
+	fn accelerate_vesting(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `428 + s * (68 ±0)`
+		//  Estimated: `6004`
+		// Minimum execution time: 121_284_000 picoseconds.
+		Weight::from_parts(146_006_113, 6004)
+			// Standard Error: 110_423
+			.saturating_add(Weight::from_parts(4_431_530, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::ScheduleEnds` (r:2 w:0)
+	/// Proof: `Vesting::ScheduleEnds` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeScheduleEnds` (r:2 w:0)
+	/// Proof: `Vesting::TgeScheduleEnds` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn cleanup_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `297`
+		//  Estimated: `6052`
+		// Minimum execution time: 37_766_000 picoseconds.
+		Weight::from_parts(41_599_000, 6052)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+	}
+	/// Storage: `Vesting::FrozenSince` (r:1 w:0)
+	/// Proof: `Vesting::FrozenSince` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::TgeBlock` (r:1 w:0)
+	/// Proof: `Vesting::TgeBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1750), added: 4225, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingAssets` (r:1 w:0)
+	/// Proof: `Vesting::VestingAssets` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 10]`.
+	fn cleanup_account(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `428 + s * (68 ±0)`
+		//  Estimated: `6004`
+		// Minimum execution time: 65_148_000 picoseconds.
+		Weight::from_parts(107_822_050, 6004)
+			// Standard Error: 78_108
+			.saturating_add(Weight::from_parts(853_860, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: UNKNOWN KEY `0x5f27b51b5ec208ee9cb25b55d87282434e7b9012096b41c4eb3aaf947f6ea429` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x5f27b51b5ec208ee9cb25b55d87282434e7b9012096b41c4eb3aaf947f6ea429` (r:1 w:0)
+	/// Storage: `Vesting::VestingSchedules` (r:2 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(2539), added: 5014, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::ScheduleEnds` (r:0 w:1)
+	/// Proof: `Vesting::ScheduleEnds` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 10]`.
+	fn migrate_v1_to_v2_step(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `155 + s * (20 ±0)`
+		//  Estimated: `11018 + s * (20 ±0)`
+		// Minimum execution time: 28_215_000 picoseconds.
+		Weight::from_parts(33_634_098, 11018)
+			// Standard Error: 62_015
+			.saturating_add(Weight::from_parts(3_250_579, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 20).saturating_mul(s.into()))
+	}
+}
@@ -35,7 +35,7 @@ use polkadot_sdk::{
 	},
 	*,
 };
-use polkadot_sdk::sp_runtime::{traits::{ConvertInto, IdentifyAccount, Verify}, MultiSignature};
+use polkadot_sdk::sp_runtime::{traits::{ConvertInto, IdentifyAccount, Verify}, MultiSignature, Perbill};
 
 /// Alias to 512-bit hash when used in the context of a transaction signature on the chain.
 pub type Signature = MultiSignature;
@@ -51,8 +51,9 @@ pub mod genesis_config_presets {
 	use crate::{
 		interface::{Balance, MinimumBalance},
 		sp_keyring::Sr25519Keyring,
-		BalancesConfig, RuntimeGenesisConfig, SudoConfig,
+		BalancesConfig, RuntimeGenesisConfig, SudoConfig, VestingConfig,
 	};
+	use pallet_vesting::{VestingCurve, VestingSchedule};
 
 	use alloc::{vec, vec::Vec};
 	use serde_json::Value;
@@ -67,6 +68,21 @@ pub mod genesis_config_presets {
 					.collect::<Vec<_>>(),
 			},
 			sudo: SudoConfig { key: Some(Sr25519Keyring::Alice.to_account_id()) },
+			vesting: VestingConfig {
+				// Half of Ferdie's endowment vests linearly over the first 1000 blocks.
+				vesting: vec![(
+					Sr25519Keyring::Ferdie.to_account_id(),
+					VestingSchedule {
+						start: 1,
+						curve: VestingCurve::Linear { duration: 1000, total: endowment / 2 },
+						grantor: None,
+						revocable: false,
+						tge_relative: false,
+						released_early: 0,
+						deposit: 0,
+					},
+				)],
+			},
 		})
 	}
 
@@ -178,6 +194,18 @@ mod runtime {
 
 	#[runtime::pallet_index(6)]
 	pub type NativePools = pallet_native_pools::Pallet<Runtime>;
+
+	/// Runs storage migrations that span several blocks.
+	#[runtime::pallet_index(7)]
+	pub type MultiBlockMigrations = pallet_migrations::Pallet<Runtime>;
+
+	/// Project tokens, which can be vested like the native token.
+	#[runtime::pallet_index(8)]
+	pub type Assets = pallet_assets::Pallet<Runtime>;
+
+	/// Lets vesting freeze balances of `Assets`.
+	#[runtime::pallet_index(9)]
+	pub type AssetsFreezer = pallet_assets_freezer::Pallet<Runtime>;
 }
 
 parameter_types! {
@@ -192,12 +220,32 @@ impl frame_system::Config for Runtime {
 	// Use the account data from the balances pallet
 	type AccountData = pallet_balances::AccountData<<Runtime as pallet_balances::Config>::Balance>;
 	type AccountId = AccountId;
+	type MultiBlockMigrator = MultiBlockMigrations;
 }
 
 // Implements the types required for the balances pallet.
 #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
 impl pallet_balances::Config for Runtime {
 	type AccountStore = System;
+	// Vesting freezes balances, so every freeze reason needs a slot.
+	type FreezeIdentifier = RuntimeFreezeReason;
+	type MaxFreezes = frame_support::traits::VariantCountOf<RuntimeFreezeReason>;
+}
+
+// Implements the types required for the assets pallet.
+#[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
+impl pallet_assets::Config for Runtime {
+	type Currency = Balances;
+	type CreateOrigin = frame_support::traits::AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	// Vesting freezes assets, so freezes have to count towards their balances.
+	type Freezer = AssetsFreezer;
+}
+
+// Implements the types required for the assets freezer pallet.
+impl pallet_assets_freezer::Config for Runtime {
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeEvent = RuntimeEvent;
 }
 
 // Implements the types required for the sudo pallet.
@@ -220,16 +268,58 @@ impl pallet_transaction_payment::Config for Runtime {
 
 parameter_types! {
     pub const MaxVestingSchedules: u32 = 10;
+    pub const MaxVestingAssets: u32 = 8;
     pub const MinVestedTransfer: u32 = 100;
+    pub const MaxVestingBatchSize: u32 = 256;
+    pub const MaxAcceptedGrantors: u32 = 16;
+    pub const VestingScheduleDeposit: u32 = 10;
+    pub const ExtendFrozenVesting: bool = true;
 }
 
 // Implements the types required for the template pallet.
 impl pallet_vesting::Config for Runtime {
-
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type Currency = Balances;
+	type AssetId = <Runtime as pallet_assets::Config>::AssetId;
+	type Assets = Assets;
+	type AssetsFreezer = AssetsFreezer;
+	// Schedules are written in local blocks, which is what `VestingApi` below reports them in.
+	type BlockNumberProvider = System;
 	type BlockNumberToBalance = ConvertInto;
 	type MaxVestingSchedules = MaxVestingSchedules;
+	type MaxVestingAssets = MaxVestingAssets;
 	type MinVestedTransfer = MinVestedTransfer;
+	type MaxBatchSize = MaxVestingBatchSize;
+	type ScheduleDeposit = VestingScheduleDeposit;
+	type ExtendFrozenVesting = ExtendFrozenVesting;
+	type MaxAcceptedGrantors = MaxAcceptedGrantors;
+	type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+	type MilestoneOrigin = frame_system::EnsureRoot<AccountId>;
+	type WeightInfo = pallet_vesting::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = VestingBenchmarkHelper;
+}
+
+/// Creates the assets the vesting benchmarks vest in.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct VestingBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_vesting::BenchmarkHelper<<Runtime as pallet_assets::Config>::AssetId>
+	for VestingBenchmarkHelper
+{
+	fn create_asset(index: u32) -> <Runtime as pallet_assets::Config>::AssetId {
+		use frame_support::traits::fungibles::{Create, Inspect};
+
+		let asset = index;
+		if !Assets::asset_exists(asset) {
+			<Assets as Create<AccountId>>::create(asset, AccountId::from([0u8; 32]), true, 1)
+				.expect("The benchmark asset can be created");
+		}
+		asset
+	}
 }
 
 parameter_types! {
@@ -238,17 +328,63 @@ parameter_types! {
 
 
 impl pallet_native_pools::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type Currency = Balances;
 	type PalletId = NativePoolsPalletId;
 	type RewardOrigin = EnsureSigned<AccountId>;
+	type WeightInfo = pallet_native_pools::weights::SubstrateWeight<Runtime>;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benches {
+	polkadot_sdk::frame_benchmarking::define_benchmarks!(
+		[pallet_vesting, Vesting]
+		[pallet_native_pools, NativePools]
+	);
+}
 
+parameter_types! {
+	pub MbmServiceWeight: Weight =
+		Perbill::from_percent(80) *
+			<<Runtime as frame_system::Config>::BlockWeights as Get<frame_system::limits::BlockWeights>>::get()
+				.max_block;
+}
+
+// Implements the types required for the multi-block migrations pallet.
+impl pallet_migrations::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type Migrations = (
+		pallet_vesting::migrations::v1::LazyMigrateLocksToFreezes<Runtime, Balances>,
+		pallet_vesting::migrations::v2::LazyMigrationV1ToV2<Runtime>,
+	);
+	// Benchmarks need mocked migrations to guarantee that they succeed.
+	#[cfg(feature = "runtime-benchmarks")]
+	type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
+	type CursorMaxLen = ConstU32<65_536>;
+	type IdentifierMaxLen = ConstU32<256>;
+	type MigrationStatusHandler = ();
+	type FailedMigrationHandler = frame_support::migrations::FreezeChainOnFailedMigration;
+	type MaxServiceWeight = MbmServiceWeight;
+	type WeightInfo = pallet_migrations::weights::SubstrateWeight<Runtime>;
 }
 
 type Block = frame::runtime::types_common::BlockOf<Runtime, TxExtension>;
 type Header = HeaderFor<Runtime>;
 
-type RuntimeExecutive =
-	Executive<Runtime, Block, frame_system::ChainContext<Runtime>, Runtime, AllPalletsWithSystem>;
+/// Single-block migrations to apply on runtime upgrade. Multi-block migrations are registered in
+/// `pallet_migrations::Config::Migrations`.
+type Migrations = ();
+
+type RuntimeExecutive = Executive<
+	Runtime,
+	Block,
+	frame_system::ChainContext<Runtime>,
+	Runtime,
+	AllPalletsWithSystem,
+	Migrations,
+>;
 
 impl_runtime_apis! {
 	impl apis::Core<Block> for Runtime {
@@ -351,6 +487,81 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(3)]
+	impl pallet_vesting::runtime_api::VestingApi<
+		Block,
+		interface::AccountId,
+		interface::BlockNumber,
+		interface::Balance,
+		interface::AssetId,
+	> for Runtime {
+		fn vesting_schedules(
+			who: interface::AccountId,
+		) -> Vec<pallet_vesting::VestingSchedule<interface::AccountId, interface::BlockNumber, interface::Balance>> {
+			Vesting::vesting_schedules(&who).into_inner()
+		}
+		fn vested_balance(who: interface::AccountId) -> interface::Balance {
+			Vesting::vested_balance(&who)
+		}
+		fn locked_balance(who: interface::AccountId) -> interface::Balance {
+			Vesting::locked_balance(&who)
+		}
+		fn claimable_balance(who: interface::AccountId) -> interface::Balance {
+			Vesting::claimable_balance(&who)
+		}
+		fn next_unlock(who: interface::AccountId) -> Option<interface::BlockNumber> {
+			Vesting::next_unlock(&who)
+		}
+		fn unlock_calendar(
+			who: interface::AccountId,
+			max_entries: u32,
+		) -> Vec<(interface::BlockNumber, interface::Balance, interface::Balance)> {
+			Vesting::unlock_calendar(&who, max_entries).into_inner()
+		}
+		fn asset_vesting_schedules(
+			asset: interface::AssetId,
+			who: interface::AccountId,
+		) -> Vec<pallet_vesting::VestingSchedule<interface::AccountId, interface::BlockNumber, interface::Balance>> {
+			Vesting::asset_vesting_schedules(asset, &who).into_inner()
+		}
+		fn asset_vested_balance(asset: interface::AssetId, who: interface::AccountId) -> interface::Balance {
+			Vesting::asset_vested_balance(asset, &who)
+		}
+		fn asset_locked_balance(asset: interface::AssetId, who: interface::AccountId) -> interface::Balance {
+			Vesting::asset_locked_balance(asset, &who)
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	impl frame_benchmarking::Benchmark<Block> for Runtime {
+		fn benchmark_metadata(extra: bool) -> (
+			Vec<frame_benchmarking::BenchmarkList>,
+			Vec<frame_support::traits::StorageInfo>,
+		) {
+			use frame_benchmarking::BenchmarkList;
+			use frame_support::traits::StorageInfoTrait;
+
+			let mut list = Vec::<BenchmarkList>::new();
+			list_benchmarks!(list, extra);
+
+			let storage_info = AllPalletsWithSystem::storage_info();
+			(list, storage_info)
+		}
+
+		fn dispatch_benchmark(
+			config: frame_benchmarking::BenchmarkConfig,
+		) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, alloc::string::String> {
+			use frame_benchmarking::BenchmarkBatch;
+			use frame_support::traits::{TrackedStorageKey, WhitelistedStorageKeys};
+
+			let whitelist: Vec<TrackedStorageKey> = AllPalletsWithSystem::whitelisted_storage_keys();
+			let mut batches = Vec::<BenchmarkBatch>::new();
+			let params = (&config, &whitelist);
+			add_benchmarks!(params, batches);
+			Ok(batches)
+		}
+	}
+
 	impl apis::GenesisBuilder<Block> for Runtime {
 		fn build_state(config: Vec<u8>) -> sp_genesis_builder::Result {
 			build_state::<RuntimeGenesisConfig>(config)
@@ -380,6 +591,8 @@ pub mod interface {
 	pub type AccountId = <Runtime as frame_system::Config>::AccountId;
 	pub type Nonce = <Runtime as frame_system::Config>::Nonce;
 	pub type Hash = <Runtime as frame_system::Config>::Hash;
+	pub type BlockNumber = frame_system::pallet_prelude::BlockNumberFor<Runtime>;
 	pub type Balance = <Runtime as pallet_balances::Config>::Balance;
+	pub type AssetId = <Runtime as pallet_assets::Config>::AssetId;
 	pub type MinimumBalance = <Runtime as pallet_balances::Config>::ExistentialDeposit;
 }